@@ -0,0 +1,32 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use throbberous::{Bar, BarConfig};
+use tokio::runtime::Runtime;
+
+fn bar_inc(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+
+    c.bench_function("bar_inc_1000", |b| {
+        b.to_async(&rt).iter(|| async {
+            let bar = Bar::with_config(1000, BarConfig::no_colors());
+            for _ in 0..1000 {
+                bar.inc(1).await;
+            }
+            bar.finish().await;
+        })
+    });
+
+    // Report the renderer's own counters for one representative run
+    rt.block_on(async {
+        let bar = Bar::with_config(1000, BarConfig::no_colors());
+        for _ in 0..1000 {
+            bar.inc(1).await;
+            tokio::task::yield_now().await;
+        }
+        bar.finish().await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        eprintln!("\n{:?}", bar.render_stats());
+    });
+}
+
+criterion_group!(benches, bar_inc);
+criterion_main!(benches);
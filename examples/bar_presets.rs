@@ -0,0 +1,25 @@
+use throbberous::{Bar, BarConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let presets = [
+        ("rocket", BarConfig::rocket()),
+        ("pacman", BarConfig::pacman()),
+        ("moon", BarConfig::moon()),
+    ];
+
+    for (name, config) in presets {
+        let bar = Bar::with_config(60, config.clone());
+        bar.set_message(name).await;
+        for _ in 0..60 {
+            bar.inc(1).await;
+            sleep(Duration::from_millis(40)).await;
+        }
+        bar.finish().await;
+
+        let bar = Bar::indeterminate_with_config(format!("{} (indeterminate)", name), config);
+        sleep(Duration::from_secs(2)).await;
+        bar.finish().await;
+    }
+}
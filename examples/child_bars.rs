@@ -0,0 +1,23 @@
+use throbberous::{Bar, BarConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Child Bars:");
+
+    let config = BarConfig::builder().advance_on_child_finish(true).build();
+    let archives = Bar::with_config(3, config);
+    archives.set_message("Extracting archives").await;
+
+    for n in 1..=3 {
+        let files = archives.add_child(20, format!("archive-{n}.tar.gz"));
+        for _i in 0..20 {
+            files.inc(1).await;
+            sleep(Duration::from_millis(40)).await;
+        }
+        files.finish().await;
+    }
+
+    // The last child finishing completes the parent
+    archives.finish().await;
+}
@@ -0,0 +1,16 @@
+use throbberous::Bar;
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Counting Bar:");
+
+    let events = Bar::counting("events handled");
+    for _i in 0..500 {
+        events.inc(7).await;
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    let report = events.finish().await;
+    println!("Handled {} events", report.total);
+}
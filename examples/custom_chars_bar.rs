@@ -0,0 +1,36 @@
+use throbberous::{Bar, BarConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Custom Characters:");
+
+    let blocks = Bar::with_config(
+        100,
+        BarConfig::builder()
+            .filled_char('█')
+            .empty_char('░')
+            .left_bracket(String::new())
+            .right_bracket(String::new())
+            .build(),
+    );
+    for _i in 0..100 {
+        blocks.inc(1).await;
+        sleep(Duration::from_millis(30)).await;
+    }
+    blocks.finish().await;
+
+    let arrow = Bar::with_config(
+        100,
+        BarConfig::builder()
+            .colors(None)
+            .head_char(Some('>'))
+            .empty_char('-')
+            .build(),
+    );
+    for _i in 0..100 {
+        arrow.inc(1).await;
+        sleep(Duration::from_millis(30)).await;
+    }
+    arrow.finish().await;
+}
@@ -0,0 +1,27 @@
+use throbberous::{BarConfig, MultiBar};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    // Too many jobs to follow as scrolling lines, so they get the whole screen
+    let multi = MultiBar::with_config(BarConfig::builder().alternate_screen(true).build());
+    multi.show_total("overall");
+
+    let mut tasks = Vec::new();
+    for job in 1..=24u64 {
+        let bar = multi.add(40, format!("job {job:>2}"));
+        tasks.push(tokio::spawn(async move {
+            for _ in 0..40 {
+                sleep(Duration::from_millis(10 * (job % 7 + 3))).await;
+                bar.inc(1).await;
+            }
+        }));
+    }
+
+    for task in tasks {
+        task.await.unwrap();
+    }
+    // Back on the regular screen, with the final frame below what was there before
+    multi.finish().await;
+    println!("All jobs done");
+}
@@ -0,0 +1,23 @@
+use throbberous::Bar;
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let files = [
+        "src/lib.rs",
+        "src/render.rs",
+        "src/animation.rs",
+        "tests/bar_test.rs",
+        "examples/detail_line.rs",
+    ];
+
+    let bar = Bar::new(files.len() as u64);
+    bar.set_message("Formatting").await;
+    for file in files {
+        bar.set_detail(format!("currently processing: {file}"))
+            .await;
+        sleep(Duration::from_millis(600)).await;
+        bar.inc(1).await;
+    }
+    bar.finish().await;
+}
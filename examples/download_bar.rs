@@ -0,0 +1,15 @@
+use throbberous::Bar;
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Download Bar:");
+
+    let download = Bar::new_bytes(256 * 1024 * 1024);
+    for _i in 0..256 {
+        download.inc(1024 * 1024).await;
+        sleep(Duration::from_millis(20)).await;
+    }
+
+    download.finish_with_message("Downloaded").await;
+}
@@ -0,0 +1,16 @@
+use throbberous::{Throbber, ThrobberConfig, ThrobberConfigBuilder};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let config = ThrobberConfigBuilder::from(ThrobberConfig::dots())
+        .show_elapsed(true)
+        .build();
+
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("Building...").await;
+    throbber.start().await;
+    // Renders e.g. `⠹ Building... 3s`
+    sleep(Duration::from_secs(4)).await;
+    throbber.stop_success("Built").await;
+}
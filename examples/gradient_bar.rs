@@ -0,0 +1,21 @@
+use throbberous::{Bar, BarConfig, ColorMode};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Gradient Progress Bar:");
+
+    let bar = Bar::with_config(
+        100,
+        BarConfig::builder()
+            .color_mode(ColorMode::red_to_green())
+            .build(),
+    );
+
+    for _i in 0..100 {
+        bar.inc(1).await;
+        sleep(Duration::from_millis(40)).await;
+    }
+
+    bar.finish().await;
+}
@@ -4,7 +4,7 @@ use tokio::time::{sleep, Duration};
 #[tokio::main]
 async fn main() {
     println!("Indeterminate Progress Bar:");
-    
+
     let loading = Bar::indeterminate("Working...");
     // Shows bouncing animation: [    ====    ]
     sleep(Duration::from_secs(6)).await;
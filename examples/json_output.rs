@@ -0,0 +1,21 @@
+use throbberous::{Bar, BarConfig, OutputMode, Throbber, ThrobberConfig};
+use tokio::time::{sleep, Duration};
+
+// Also available for any program through `THROBBEROUS_OUTPUT=json`
+#[tokio::main]
+async fn main() {
+    let throbber =
+        Throbber::with_config(ThrobberConfig::builder().output(OutputMode::Json).build());
+    throbber.set_message("Resolving dependencies").await;
+    throbber.start().await;
+    sleep(Duration::from_millis(500)).await;
+    throbber.stop_success("Resolved").await;
+
+    let bar = Bar::with_config(5, BarConfig::builder().output(OutputMode::Json).build());
+    bar.set_message("Downloading").await;
+    for _ in 0..5 {
+        sleep(Duration::from_millis(200)).await;
+        bar.inc(1).await;
+    }
+    bar.finish().await;
+}
@@ -0,0 +1,29 @@
+use throbberous::MultiBar;
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Multi Bar:");
+
+    let multi = MultiBar::new();
+    multi.set_remove_finished(true);
+    multi.show_total("overall");
+
+    let mut tasks = Vec::new();
+    for job in 1..=6u64 {
+        let bar = multi.add(20, format!("job {job}"));
+        tasks.push(tokio::spawn(async move {
+            for _i in 0..20 {
+                bar.inc(1).await;
+                sleep(Duration::from_millis(20 * job)).await;
+            }
+        }));
+        sleep(Duration::from_millis(300)).await;
+    }
+
+    for task in tasks {
+        task.await.unwrap();
+    }
+    multi.finish().await;
+    println!("All jobs done");
+}
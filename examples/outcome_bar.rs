@@ -0,0 +1,18 @@
+use throbberous::Bar;
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let bar = Bar::new(100);
+    bar.set_message("Running tests").await;
+
+    for i in 0..100 {
+        sleep(Duration::from_millis(30)).await;
+        if i % 17 == 0 {
+            bar.inc_failed(1).await;
+        } else {
+            bar.inc_ok(1).await;
+        }
+    }
+    bar.finish().await;
+}
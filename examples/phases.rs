@@ -0,0 +1,23 @@
+use crossterm::style::Color;
+use throbberous::{Bar, BarConfig, Phase};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let bar = Bar::with_config(
+        100,
+        BarConfig::builder()
+            .phases(vec![
+                Phase::new("download", 6.0, Some(Color::Cyan)),
+                Phase::new("verify", 1.0, Some(Color::Yellow)),
+                Phase::new("extract", 3.0, Some(Color::Green)),
+            ])
+            .build(),
+    );
+
+    for _ in 0..100 {
+        sleep(Duration::from_millis(40)).await;
+        bar.inc(1).await;
+    }
+    bar.finish().await;
+}
@@ -0,0 +1,17 @@
+use throbberous::{Bar, BarConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let bar = Bar::with_config(20, BarConfig::builder().pin_to_bottom(true).build());
+    bar.set_message("Unpacking").await;
+
+    for i in 1..=20 {
+        // Plain stdout output scrolls above the bar, no `throbberous::println` needed
+        println!("Unpacking package-{i} ...");
+        sleep(Duration::from_millis(150)).await;
+        bar.inc(1).await;
+    }
+    bar.finish().await;
+    println!("All packages unpacked");
+}
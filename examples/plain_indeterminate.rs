@@ -11,4 +11,3 @@ async fn main() {
     loading.finish().await;
     println!("Done!");
 }
-
@@ -0,0 +1,16 @@
+use throbberous::Bar;
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Printing Above a Bar:");
+
+    let bar = Bar::new(10);
+    for file in 1..=10 {
+        sleep(Duration::from_millis(200)).await;
+        // Scrolls up above the bar instead of being drawn over
+        throbberous::println(format!("compiled file{file}.rs"));
+        bar.inc(1).await;
+    }
+    bar.finish().await;
+}
@@ -0,0 +1,15 @@
+use throbberous::Bar;
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    // Terminal state is restored when the session drops, on panic, or on Ctrl-C
+    let _session = throbberous::init().handle_signals();
+
+    let bar = Bar::new(100);
+    for _i in 0..100 {
+        bar.inc(1).await;
+        sleep(Duration::from_millis(20)).await;
+    }
+    bar.finish().await;
+}
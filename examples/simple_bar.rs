@@ -1,18 +1,23 @@
-use throbberous::Bar;
+use std::sync::Arc;
+
+use throbberous::{Bar, BarConfig};
 use tokio::time::{sleep, Duration};
 
 #[tokio::main]
 async fn main() {
     println!("Simple Progress Bar Demo:");
-    
-    let bar = Bar::new(100);
-    
+
+    // Opt into milestone messages: "Working..." -> "Quarter done" -> "Halfway done" -> "Almost there..." -> "Complete!"
+    let config = BarConfig::builder()
+        .message_provider(Some(Arc::new(Bar::milestone_message)))
+        .build();
+    let bar = Bar::with_config(100, config);
+
     for _i in 0..100 {
         bar.inc(1).await;
         sleep(Duration::from_millis(50)).await;
-        // Messages automatically change: "Working..." -> "Quarter done" -> "Halfway done" -> "Almost there..." -> "Complete!"
     }
-    
+
     bar.finish().await;
     println!("Done!");
 }
@@ -4,7 +4,7 @@ use tokio::time::{sleep, Duration};
 #[tokio::main]
 async fn main() {
     println!("Throbber");
-    
+
     let throbber = Throbber::new();
     throbber.start().await;
     // Spins: | / - \ with "Working..."
@@ -0,0 +1,17 @@
+use throbberous::{Bar, BarConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Smooth Progress Bar:");
+
+    let bar = Bar::with_config(5000, BarConfig::builder().smooth(true).build());
+
+    for _i in 0..5000 {
+        bar.inc(1).await;
+        sleep(Duration::from_millis(1)).await;
+    }
+
+    bar.finish().await;
+    println!("Done!");
+}
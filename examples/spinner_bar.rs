@@ -0,0 +1,26 @@
+use throbberous::{presets, Bar, BarConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let bar = Bar::with_config(
+        100,
+        BarConfig::builder()
+            .spinner(Some(
+                presets::DOTS
+                    .iter()
+                    .map(|frame| frame.to_string())
+                    .collect(),
+            ))
+            .build(),
+    );
+    bar.set_message("Uploading").await;
+
+    for i in 0..100 {
+        // Stalls halfway; the spinner keeps turning meanwhile
+        let delay = if i == 50 { 2000 } else { 30 };
+        sleep(Duration::from_millis(delay)).await;
+        bar.inc(1).await;
+    }
+    bar.finish().await;
+}
@@ -0,0 +1,22 @@
+use throbberous::{Throbber, ThrobberConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let presets = [
+        ("dots", ThrobberConfig::dots()),
+        ("line", ThrobberConfig::line()),
+        ("arc", ThrobberConfig::arc()),
+        ("moon", ThrobberConfig::moon()),
+        ("bouncing_ball", ThrobberConfig::bouncing_ball()),
+        ("clock", ThrobberConfig::clock()),
+    ];
+
+    for (name, config) in presets {
+        let throbber = Throbber::with_config(config);
+        throbber.set_message(name).await;
+        throbber.start().await;
+        sleep(Duration::from_secs(2)).await;
+        throbber.stop_success(name).await;
+    }
+}
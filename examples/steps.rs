@@ -0,0 +1,24 @@
+use throbberous::{presets, Steps, ThrobberConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let steps = Steps::with_config(
+        ["Fetching", "Compiling", "Linking"],
+        ThrobberConfig::builder()
+            .frames(
+                presets::DOTS
+                    .iter()
+                    .map(|frame| frame.to_string())
+                    .collect(),
+            )
+            .frame_delay(80)
+            .build(),
+    );
+
+    for step in ["Fetching", "Compiling", "Linking"] {
+        steps.next(step).await;
+        sleep(Duration::from_millis(1200)).await;
+    }
+    steps.finish().await;
+}
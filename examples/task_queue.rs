@@ -0,0 +1,26 @@
+use throbberous::TaskQueue;
+use tokio::time::{sleep, Duration};
+
+async fn work(millis: u64, result: Result<(), &'static str>) -> Result<(), &'static str> {
+    sleep(Duration::from_millis(millis)).await;
+    result
+}
+
+#[tokio::main]
+async fn main() {
+    let mut queue = TaskQueue::new();
+    queue
+        .push("Building release", work(1200, Ok(())))
+        .push("Uploading artifacts", work(900, Ok(())))
+        .push("Migrating database", work(1500, Err("lock timeout")))
+        .push("Restarting services", work(800, Ok(())));
+
+    let report = queue.run().await;
+    for task in report.tasks.iter().filter(|task| task.error.is_some()) {
+        eprintln!(
+            "{}: {}",
+            task.name,
+            task.error.as_deref().unwrap_or_default()
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crossterm::style::Color;
+use throbberous::{Bar, BarConfig, TextStyle, Theme};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let bar = Bar::with_config(
+        100,
+        BarConfig::builder()
+            .filled_char(' ')
+            .theme(Theme {
+                filled_style: TextStyle {
+                    background: Some(Color::DarkGreen),
+                    ..TextStyle::default()
+                },
+                empty_style: TextStyle {
+                    background: Some(Color::DarkGrey),
+                    ..TextStyle::default()
+                },
+                message_style: TextStyle {
+                    bold: true,
+                    ..TextStyle::default()
+                },
+                ..Theme::default()
+            })
+            .build(),
+    );
+    bar.set_message("Rendering").await;
+
+    for _ in 0..100 {
+        sleep(Duration::from_millis(30)).await;
+        bar.inc(1).await;
+    }
+    bar.finish().await;
+}
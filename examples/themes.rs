@@ -0,0 +1,32 @@
+use throbberous::{Bar, BarConfig, Theme, Throbber, ThrobberConfig, ThrobberConfigBuilder};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    let themes = [
+        ("monochrome", Theme::monochrome()),
+        ("ocean", Theme::ocean()),
+        ("forest", Theme::forest()),
+        ("sunset", Theme::sunset()),
+    ];
+
+    for (name, theme) in themes {
+        let bar = Bar::with_config(50, BarConfig::builder().theme(theme.clone()).build());
+        bar.set_message(name).await;
+        for _i in 0..50 {
+            bar.inc(1).await;
+            sleep(Duration::from_millis(20)).await;
+        }
+        bar.finish().await;
+
+        let throbber = Throbber::with_config(
+            ThrobberConfigBuilder::from(ThrobberConfig::dots())
+                .theme(theme)
+                .build(),
+        );
+        throbber.set_message(name).await;
+        throbber.start().await;
+        sleep(Duration::from_secs(1)).await;
+        throbber.stop_err(format!("{name} error")).await;
+    }
+}
@@ -0,0 +1,37 @@
+use throbberous::{shared_animator, Bar, BarConfig, Throbber, ThrobberConfig};
+use tokio::time::{sleep, Duration};
+
+const WAVE: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+fn wave(tick: u64, width: usize) -> String {
+    (0..width)
+        .map(|i| {
+            let phase = (tick as usize + i) % (2 * WAVE.len() - 2);
+            WAVE[phase.min(2 * WAVE.len() - 2 - phase)]
+        })
+        .collect()
+}
+
+#[tokio::main]
+async fn main() {
+    let bar = Bar::indeterminate_with_config(
+        "Waiting for the queue...",
+        BarConfig::builder()
+            .animator(Some(shared_animator(wave)))
+            .build(),
+    );
+    sleep(Duration::from_secs(3)).await;
+    bar.finish_with_message("Queue drained").await;
+
+    let throbber = Throbber::with_config(
+        ThrobberConfig::builder()
+            .animator(Some(shared_animator(wave)))
+            .animation_width(6)
+            .frame_delay(80)
+            .build(),
+    );
+    throbber.set_message("Listening...").await;
+    throbber.start().await;
+    sleep(Duration::from_secs(3)).await;
+    throbber.stop_success("Done").await;
+}
@@ -0,0 +1,71 @@
+//! Getting the user's attention when a widget finishes, see [`FinishAlert`]
+
+use crate::{DrawTarget, OutputMode, RendererFactory};
+
+/// How a bar or throbber calls the user back once it finishes or fails
+///
+/// Off by default. For long jobs left running in a background terminal:
+///
+/// ```rust
+/// use throbberous::{BarConfig, FinishAlert};
+///
+/// let config = BarConfig::builder().alert(FinishAlert::bell()).build();
+/// assert!(config.alert.bell);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FinishAlert {
+    /// Rings the terminal bell; only when the widget draws to a terminal
+    pub bell: bool,
+    /// Shows a desktop notification with the widget's final line
+    #[cfg(feature = "desktop-notify")]
+    pub desktop: bool,
+}
+
+impl FinishAlert {
+    /// Just the terminal bell
+    pub fn bell() -> Self {
+        Self {
+            bell: true,
+            #[cfg(feature = "desktop-notify")]
+            desktop: false,
+        }
+    }
+
+    /// Just a desktop notification
+    #[cfg(feature = "desktop-notify")]
+    pub fn desktop() -> Self {
+        Self {
+            bell: false,
+            desktop: true,
+        }
+    }
+}
+
+/// Raises `alert` for a widget whose final line reads `text`
+pub(crate) fn raise(
+    alert: FinishAlert,
+    renderer: Option<&RendererFactory>,
+    output: OutputMode,
+    target: DrawTarget,
+    text: &str,
+) {
+    let output = output.resolve_for(target);
+    // Custom renderers and other outputs have no terminal to ring
+    if alert.bell && renderer.is_none() && output == OutputMode::Terminal && target.is_terminal() {
+        // Nowhere to report a failed bell to; the next frame's write will notice
+        let _ = target.write_all(b"\x07");
+    }
+
+    #[cfg(feature = "desktop-notify")]
+    if alert.desktop && output != OutputMode::Hidden {
+        let text = text.to_string();
+        // Talking to the notification daemon blocks
+        std::thread::spawn(move || {
+            let _ = notify_rust::Notification::new().summary(&text).show();
+        });
+    }
+    #[cfg(not(feature = "desktop-notify"))]
+    let _ = text;
+}
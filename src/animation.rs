@@ -0,0 +1,140 @@
+//! Frame generators for indeterminate bars and throbbers
+
+use std::sync::{Arc, Mutex as StdMutex};
+
+use unicode_width::UnicodeWidthChar;
+
+/// A custom frame generator driven by an indeterminate bar or a throbber
+///
+/// `frame` is called once per animation tick, with `tick` counting up from
+/// zero each time the widget (re)starts. Bars ask for exactly `width` columns
+/// and pad or cut whatever comes back to fit; throbbers draw the frame as is.
+/// Closures of the form `FnMut(u64, usize) -> String` are animators too.
+pub trait Animator: Send {
+    fn frame(&mut self, tick: u64, width: usize) -> String;
+}
+
+impl<F> Animator for F
+where
+    F: FnMut(u64, usize) -> String + Send,
+{
+    fn frame(&mut self, tick: u64, width: usize) -> String {
+        self(tick, width)
+    }
+}
+
+/// An [`Animator`] that can be stored in a (cloneable) widget config
+pub type SharedAnimator = Arc<StdMutex<dyn Animator>>;
+
+/// Wraps `animator` for use as `BarConfig::animator` or `ThrobberConfig::animator`
+pub fn shared_animator(animator: impl Animator + 'static) -> SharedAnimator {
+    Arc::new(StdMutex::new(animator))
+}
+
+/// Asks `animator` for the frame on `tick`
+pub(crate) fn frame(animator: &SharedAnimator, tick: u64, width: usize) -> String {
+    animator
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .frame(tick, width)
+}
+
+/// Cuts or pads `frame` with `empty` to exactly `width` columns
+pub(crate) fn fit_cells(frame: &str, width: usize, empty: char) -> String {
+    let mut used = 0;
+    let mut cells: String = frame
+        .chars()
+        .take_while(|c| {
+            used += c.width().unwrap_or(0);
+            used <= width
+        })
+        .collect();
+    let drawn: usize = cells.chars().map(|c| c.width().unwrap_or(0)).sum();
+    cells.extend(std::iter::repeat_n(empty, width.saturating_sub(drawn)));
+    cells
+}
+
+/// How an indeterminate bar animates
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum IndeterminateStyle {
+    /// A block bouncing between the edges
+    #[default]
+    Bounce,
+    /// A block sliding to the right and wrapping around
+    Marquee,
+    /// A centered block growing to the full width and shrinking back
+    Pulse,
+    /// A bright spot with a fading tail sweeping back and forth, e.g. `░▒▓█▓▒░`
+    Scan,
+}
+
+/// Shading from the center of the scan spot outwards
+const SCAN_SHADES: [char; 4] = ['█', '▓', '▒', '░'];
+
+/// [`SCAN_SHADES`] for terminals without block characters
+const ASCII_SCAN_SHADES: [char; 4] = ['#', '=', '-', '.'];
+
+impl IndeterminateStyle {
+    /// The bar's cells on animation tick `tick` for a bar `width` cells wide
+    pub(crate) fn cells(
+        self,
+        tick: u64,
+        width: usize,
+        filled: char,
+        empty: char,
+        unicode: bool,
+    ) -> String {
+        let width = width.max(1);
+        let block = (width / 4 + 1).min(width);
+        let mut cells = vec![empty; width];
+
+        match self {
+            IndeterminateStyle::Bounce => {
+                let start = triangle(tick, width - block);
+                cells[start..start + block].fill(filled);
+            }
+            IndeterminateStyle::Marquee => {
+                let start = (tick % width as u64) as usize;
+                for offset in 0..block {
+                    cells[(start + offset) % width] = filled;
+                }
+            }
+            IndeterminateStyle::Pulse => {
+                let size = triangle(tick, width);
+                let start = (width - size) / 2;
+                cells[start..start + size].fill(filled);
+            }
+            IndeterminateStyle::Scan => {
+                let center = triangle(tick, width - 1);
+                let shades = if unicode {
+                    SCAN_SHADES
+                } else {
+                    ASCII_SCAN_SHADES
+                };
+                for (i, cell) in cells.iter_mut().enumerate() {
+                    if let Some(&shade) = shades.get(i.abs_diff(center)) {
+                        *cell = shade;
+                    }
+                }
+            }
+        }
+
+        cells.into_iter().collect()
+    }
+}
+
+/// Goes 0, 1, ..., `max`, ..., 1, 0, 1, ... as `tick` increases
+fn triangle(tick: u64, max: usize) -> usize {
+    if max == 0 {
+        return 0;
+    }
+    let period = 2 * max as u64;
+    let phase = (tick % period) as usize;
+    if phase <= max {
+        phase
+    } else {
+        2 * max - phase
+    }
+}
@@ -0,0 +1,229 @@
+//! Chainable builders for [`BarConfig`] and [`ThrobberConfig`]
+//!
+//! An alternative to struct literals with `..Default::default()`: start from
+//! `BarConfig::builder()` (or any existing config via `From`), set what
+//! differs and `build()`.
+
+use crossterm::style::Color;
+
+#[cfg(feature = "cancellation")]
+use crate::CancellationToken;
+use crate::{
+    BarConfig, CancelAction, Charset, ColorChoice, ColorMode, CountDisplay, Deadline, DrawTarget,
+    ErrorHook, FinishAlert, IndeterminateStyle, LineFormatter, MessageProvider, OutputMode, Phase,
+    RendererFactory, SharedAnimator, SpinnerPlacement, StatusTheme, Strings, Theme, Throbber,
+    ThrobberConfig, Truncation, Units, ZeroTotal,
+};
+
+/// One setter per config field, each documented by a link to the field
+macro_rules! setters {
+    ($config:ident { $($(#[$attr:meta])* $field:ident: $ty:ty,)* }) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("Sets [`", stringify!($config), "::", stringify!($field), "`]")]
+            pub fn $field(mut self, $field: $ty) -> Self {
+                self.config.$field = $field;
+                self
+            }
+        )*
+    };
+}
+
+/// Builds a [`BarConfig`], see [`BarConfig::builder`]
+///
+/// ```rust
+/// use throbberous::{BarConfig, Units};
+///
+/// let config = BarConfig::builder()
+///     .width(60)
+///     .colors(None)
+///     .units(Units::Bytes)
+///     .build();
+/// assert_eq!(config.width, 60);
+/// ```
+#[derive(Clone, Default)]
+pub struct BarConfigBuilder {
+    config: BarConfig,
+}
+
+impl BarConfigBuilder {
+    setters!(BarConfig {
+        colors: Option<Vec<Color>>,
+        color_choice: ColorChoice,
+        color_cycle_delay: u64,
+        color_mode: ColorMode,
+        theme: Theme,
+        width: usize,
+        fit_to_terminal: bool,
+        truncation: Truncation,
+        units: Units,
+        count_display: CountDisplay,
+        zero_total: ZeroTotal,
+        show_percent: bool,
+        percent_precision: usize,
+        count_label: Option<String>,
+        show_rate: bool,
+        show_eta: bool,
+        rate_smoothing: f64,
+        unit_precision: usize,
+        filled_char: char,
+        empty_char: char,
+        head_char: Option<char>,
+        phases: Vec<Phase>,
+        spinner: Option<Vec<String>>,
+        indeterminate_style: IndeterminateStyle,
+        manual_tick: bool,
+        refresh_rate: u32,
+        animator: Option<SharedAnimator>,
+        smooth: bool,
+        right_to_left: bool,
+        charset: Charset,
+        left_bracket: String,
+        right_bracket: String,
+        status_theme: StatusTheme,
+        advance_on_child_finish: bool,
+        #[cfg(feature = "cancellation")]
+        cancel_token: Option<CancellationToken>,
+        on_cancel: CancelAction,
+        alert: FinishAlert,
+        hide_cursor: bool,
+        pin_to_bottom: bool,
+        alternate_screen: bool,
+        deadline: Option<Deadline>,
+        on_error: Option<ErrorHook>,
+        renderer: Option<RendererFactory>,
+        output: OutputMode,
+        target: DrawTarget,
+        formatter: Option<LineFormatter>,
+        message_provider: Option<MessageProvider>,
+        strings: Strings,
+        label: Option<String>,
+    });
+
+    pub fn build(self) -> BarConfig {
+        self.config
+    }
+}
+
+impl From<BarConfig> for BarConfigBuilder {
+    fn from(config: BarConfig) -> Self {
+        Self { config }
+    }
+}
+
+/// Builds a [`ThrobberConfig`], see [`ThrobberConfig::builder`]
+#[derive(Clone, Default)]
+pub struct ThrobberConfigBuilder {
+    config: ThrobberConfig,
+}
+
+impl ThrobberConfigBuilder {
+    setters!(ThrobberConfig {
+        frames: Vec<String>,
+        animator: Option<SharedAnimator>,
+        animation_width: usize,
+        colors: Option<Vec<Color>>,
+        color_choice: ColorChoice,
+        frame_delay: u64,
+        spinner_placement: SpinnerPlacement,
+        manual_tick: bool,
+        fit_to_terminal: bool,
+        truncation: Truncation,
+        theme: Theme,
+        status_theme: StatusTheme,
+        #[cfg(feature = "cancellation")]
+        cancel_token: Option<CancellationToken>,
+        on_cancel: CancelAction,
+        alert: FinishAlert,
+        hide_cursor: bool,
+        charset: Charset,
+        show_elapsed: bool,
+        deadline: Option<Deadline>,
+        on_error: Option<ErrorHook>,
+        renderer: Option<RendererFactory>,
+        output: OutputMode,
+        target: DrawTarget,
+        strings: Strings,
+        label: Option<String>,
+    });
+
+    pub fn build(self) -> ThrobberConfig {
+        self.config
+    }
+}
+
+impl From<ThrobberConfig> for ThrobberConfigBuilder {
+    fn from(config: ThrobberConfig) -> Self {
+        Self { config }
+    }
+}
+
+/// Creates a [`Throbber`] showing its message from the first frame, see [`Throbber::builder`]
+///
+/// ```rust,no_run
+/// # async fn example() {
+/// use throbberous::Throbber;
+///
+/// let throbber = Throbber::builder()
+///     .message("Resolving dependencies")
+///     .auto_start(true)
+///     .spawn();
+/// throbber.stop_success("Resolved").await;
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct ThrobberBuilder {
+    config: ThrobberConfig,
+    /// `None` shows the config's `strings.throbbing`
+    message: Option<String>,
+    auto_start: bool,
+}
+
+impl ThrobberBuilder {
+    /// The config the throbber is drawn with, the default one unless set
+    pub fn config(mut self, config: ThrobberConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The message shown next to the spinner
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Whether the throbber starts spinning right away, without [`Throbber::start`]
+    pub fn auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Creates the throbber
+    pub fn spawn(self) -> Throbber {
+        let message = self
+            .message
+            .unwrap_or_else(|| self.config.strings.throbbing.clone());
+        Throbber::spawn(self.config, message, self.auto_start)
+    }
+}
+
+impl Throbber {
+    /// Starts a [`ThrobberBuilder`], for a throbber with its message set up front
+    pub fn builder() -> ThrobberBuilder {
+        ThrobberBuilder::default()
+    }
+}
+
+impl BarConfig {
+    /// Starts a [`BarConfigBuilder`] from the default config
+    pub fn builder() -> BarConfigBuilder {
+        BarConfigBuilder::default()
+    }
+}
+
+impl ThrobberConfig {
+    /// Starts a [`ThrobberConfigBuilder`] from the default config
+    pub fn builder() -> ThrobberConfigBuilder {
+        ThrobberConfigBuilder::default()
+    }
+}
@@ -0,0 +1,86 @@
+//! Stopping widgets when a `CancellationToken` is cancelled (feature `cancellation`)
+//!
+//! Without the feature no token can be configured and draw tasks only wake
+//! up for redraws.
+
+use std::time::Instant;
+
+use crate::{BarConfig, RenderSignal, ThrobberConfig};
+
+#[cfg(feature = "cancellation")]
+pub(crate) use tokio_util::sync::CancellationToken as Token;
+
+/// Stand-in for the token type when the feature is off; never constructed
+#[cfg(not(feature = "cancellation"))]
+pub(crate) enum Token {}
+
+/// What a widget leaves on its line when its cancellation token fires
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CancelAction {
+    /// Erase the line(s) as if the widget was never there
+    Clear,
+    /// Leave the last frame as drawn and move to a fresh line
+    Abandon,
+    /// Redraw once with [`Status::Cancelled`](crate::Status::Cancelled) styling
+    #[default]
+    MarkCancelled,
+}
+
+/// Why a draw task woke up
+pub(crate) enum Wake {
+    Redraw,
+    #[cfg_attr(not(feature = "cancellation"), allow(dead_code))]
+    Cancelled,
+}
+
+/// Waits for the next redraw request, or for `token` to be cancelled
+pub(crate) async fn wait(signal: &RenderSignal, token: Option<&Token>) -> Wake {
+    match token {
+        #[cfg(feature = "cancellation")]
+        Some(token) => tokio::select! {
+            _ = signal.notified() => Wake::Redraw,
+            _ = token.cancelled() => Wake::Cancelled,
+        },
+        #[cfg(not(feature = "cancellation"))]
+        Some(token) => match *token {},
+        None => {
+            signal.notified().await;
+            Wake::Redraw
+        }
+    }
+}
+
+/// Waits until the next frame is due at `until`, or for `token` to be cancelled
+pub(crate) async fn pace(until: Instant, token: Option<&Token>) {
+    let due = tokio::time::sleep_until(until.into());
+    match token {
+        #[cfg(feature = "cancellation")]
+        Some(token) => tokio::select! {
+            _ = due => {}
+            _ = token.cancelled() => {}
+        },
+        #[cfg(not(feature = "cancellation"))]
+        Some(token) => match *token {},
+        None => due.await,
+    }
+}
+
+impl BarConfig {
+    pub(crate) fn cancel_token(&self) -> Option<&Token> {
+        #[cfg(feature = "cancellation")]
+        return self.cancel_token.as_ref();
+        #[cfg(not(feature = "cancellation"))]
+        return None;
+    }
+}
+
+impl ThrobberConfig {
+    pub(crate) fn cancel_token(&self) -> Option<&Token> {
+        #[cfg(feature = "cancellation")]
+        return self.cancel_token.as_ref();
+        #[cfg(not(feature = "cancellation"))]
+        return None;
+    }
+}
@@ -0,0 +1,205 @@
+//! Terminal capability detection
+//!
+//! Probed once per process from the environment and cached.
+
+use std::{env, sync::OnceLock};
+
+use crossterm::style::Color;
+
+use crate::{color, DrawTarget};
+
+/// Whether the terminal can be expected to render non-ASCII glyphs like `✓`/`✗`
+pub fn supports_unicode() -> bool {
+    static UNICODE: OnceLock<bool> = OnceLock::new();
+    *UNICODE.get_or_init(detect_unicode)
+}
+
+#[cfg(not(windows))]
+fn detect_unicode() -> bool {
+    // The Linux virtual console only ships a tiny glyph set
+    if env::var("TERM").is_ok_and(|term| term == "linux") {
+        return false;
+    }
+
+    // First non-empty locale variable wins, as in setlocale(3)
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .is_some_and(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+#[cfg(windows)]
+fn detect_unicode() -> bool {
+    // Modern hosts set one of these; the legacy console doesn't
+    env::var_os("WT_SESSION").is_some()
+        || env::var_os("TERM_PROGRAM").is_some()
+        || env::var("ConEmuANSI").is_ok_and(|value| value == "ON")
+        || env::var("TERM").is_ok_and(|term| term.contains("xterm"))
+}
+
+/// Which characters widgets draw with
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Charset {
+    /// Unicode where [`supports_unicode`] detects it, ASCII otherwise
+    #[default]
+    Auto,
+    /// Block characters, braille spinners and `…`
+    Unicode,
+    /// `#`/`-` blocks, `-\|/` spinners and `...`, for legacy consoles
+    Ascii,
+}
+
+impl Charset {
+    pub fn unicode(self) -> bool {
+        match self {
+            Charset::Auto => supports_unicode(),
+            Charset::Unicode => true,
+            Charset::Ascii => false,
+        }
+    }
+}
+
+/// Whether widgets should emit colors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ColorChoice {
+    /// Follow `NO_COLOR`, `CLICOLOR_FORCE`, `CLICOLOR` and whether the
+    /// [`DrawTarget`] is a terminal
+    #[default]
+    Auto,
+    /// Always emit colors
+    Always,
+    /// Never emit colors
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether colors are emitted on the default [`DrawTarget`]
+    pub fn enabled(self) -> bool {
+        self.enabled_for(DrawTarget::default())
+    }
+
+    /// Whether colors are emitted on `target`
+    pub fn enabled_for(self, target: DrawTarget) -> bool {
+        match self {
+            ColorChoice::Auto => colors_enabled_for(target),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// Color support on the default [`DrawTarget`] as decided by the environment
+///
+/// `NO_COLOR` (any non-empty value) disables colors, `CLICOLOR_FORCE` (anything
+/// but `0`) forces them on even when piped, `CLICOLOR=0` disables them, and
+/// otherwise colors are used only when the target is a terminal.
+pub fn colors_enabled() -> bool {
+    colors_enabled_for(DrawTarget::default())
+}
+
+/// Like [`colors_enabled`], for `target`
+pub fn colors_enabled_for(target: DrawTarget) -> bool {
+    static COLORS: [OnceLock<bool>; 2] = [OnceLock::new(), OnceLock::new()];
+    *COLORS[target as usize].get_or_init(|| detect_colors(target))
+}
+
+fn detect_colors(target: DrawTarget) -> bool {
+    let set = |name: &str| env::var(name).is_ok_and(|value| !value.is_empty());
+
+    if set("NO_COLOR") {
+        return false;
+    }
+    if set("CLICOLOR_FORCE") && env::var("CLICOLOR_FORCE").as_deref() != Ok("0") {
+        return true;
+    }
+    if env::var("CLICOLOR").is_ok_and(|value| value == "0") {
+        return false;
+    }
+
+    target.is_terminal()
+}
+
+/// How many colors the terminal can show
+///
+/// Colors beyond the depth are drawn as the nearest one it has, see
+/// [`ColorDepth::downsample`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ColorDepth {
+    /// The 16 named colors
+    Ansi16,
+    /// The 256-color palette, `Color::AnsiValue`
+    Ansi256,
+    /// Any `Color::Rgb`
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// The closest color to `color` this depth can show
+    ///
+    /// Named colors are kept as they are, since every terminal has them.
+    pub fn downsample(self, color: Color) -> Color {
+        color::downsample(color, self)
+    }
+}
+
+/// Color depth of the terminal, from `COLORTERM` and `TERM`
+///
+/// `COLORTERM=truecolor` (or `24bit`) means true color, a `TERM` like
+/// `xterm-256color` means 256 colors, and anything else only the 16 named ones.
+pub fn color_depth() -> ColorDepth {
+    static DEPTH: OnceLock<ColorDepth> = OnceLock::new();
+    *DEPTH.get_or_init(detect_color_depth)
+}
+
+fn detect_color_depth() -> ColorDepth {
+    let colorterm = env::var("COLORTERM")
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    if colorterm == "truecolor" || colorterm == "24bit" {
+        return ColorDepth::TrueColor;
+    }
+
+    let term = env::var("TERM").unwrap_or_default().to_ascii_lowercase();
+    if term.ends_with("-direct") || term.contains("truecolor") || term.contains("24bit") {
+        return ColorDepth::TrueColor;
+    }
+    // Windows Terminal and ConEmu render RGB but don't always say so
+    if cfg!(windows) && (env::var_os("WT_SESSION").is_some() || env::var_os("ConEmuANSI").is_some())
+    {
+        return ColorDepth::TrueColor;
+    }
+    if term.contains("256") {
+        return ColorDepth::Ansi256;
+    }
+    ColorDepth::Ansi16
+}
+
+/// Current terminal width in columns, if there is a terminal
+///
+/// Not cached, so callers see the new width after a resize.
+pub fn terminal_width() -> Option<usize> {
+    crossterm::terminal::size()
+        .ok()
+        .map(|(columns, _)| columns as usize)
+        .filter(|&columns| columns > 0)
+}
+
+/// Current terminal height in rows, if there is a terminal
+///
+/// Not cached, like [`terminal_width`].
+pub fn terminal_height() -> Option<usize> {
+    crossterm::terminal::size()
+        .ok()
+        .map(|(_, rows)| rows as usize)
+        .filter(|&rows| rows > 0)
+}
@@ -0,0 +1,101 @@
+//! Common progress flags for clap-based CLIs (feature `clap`)
+//!
+//! Flatten [`ProgressArgs`] into the application's arguments and build the
+//! widgets' configs from it instead of mapping the flags by hand.
+
+use crate::{
+    BarConfig, BarConfigBuilder, ColorChoice, OutputMode, ThrobberConfig, ThrobberConfigBuilder,
+};
+
+/// `--quiet`, `--no-progress` and `--plain`, ready to `#[command(flatten)]`
+///
+/// ```rust
+/// use clap::Parser;
+/// use throbberous::{ProgressArgs, ProgressStyle};
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     progress: ProgressArgs,
+/// }
+///
+/// let cli = Cli::parse_from(["mytool", "--plain"]);
+/// assert_eq!(cli.progress.style(), ProgressStyle::Plain);
+/// let config = cli.progress.bar_config();
+/// assert!(config.colors.is_none());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::Args)]
+pub struct ProgressArgs {
+    /// Don't show progress bars or spinners
+    #[arg(short, long)]
+    pub quiet: bool,
+    /// Don't show progress bars or spinners
+    #[arg(long)]
+    pub no_progress: bool,
+    /// Show progress without colors
+    #[arg(long)]
+    pub plain: bool,
+}
+
+/// How widgets are shown, as chosen by [`ProgressArgs`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProgressStyle {
+    /// Nothing is drawn
+    Hidden,
+    /// Drawn without colors
+    Plain,
+    /// Drawn as configured by default
+    #[default]
+    Colored,
+}
+
+impl ProgressArgs {
+    /// The style the flags ask for; hiding wins over `--plain`
+    pub fn style(&self) -> ProgressStyle {
+        if self.quiet || self.no_progress {
+            ProgressStyle::Hidden
+        } else if self.plain {
+            ProgressStyle::Plain
+        } else {
+            ProgressStyle::Colored
+        }
+    }
+
+    /// The default bar config adjusted to the flags
+    pub fn bar_config(&self) -> BarConfig {
+        self.apply_to_bar(BarConfig::default())
+    }
+
+    /// The default throbber config adjusted to the flags
+    pub fn throbber_config(&self) -> ThrobberConfig {
+        self.apply_to_throbber(ThrobberConfig::default())
+    }
+
+    /// `config` with its output and colors adjusted to the flags
+    pub fn apply_to_bar(&self, config: BarConfig) -> BarConfig {
+        match self.style() {
+            ProgressStyle::Hidden => BarConfigBuilder::from(config)
+                .output(OutputMode::Hidden)
+                .build(),
+            ProgressStyle::Plain => BarConfigBuilder::from(config)
+                .colors(None)
+                .color_choice(ColorChoice::Never)
+                .build(),
+            ProgressStyle::Colored => config,
+        }
+    }
+
+    /// `config` with its output and colors adjusted to the flags
+    pub fn apply_to_throbber(&self, config: ThrobberConfig) -> ThrobberConfig {
+        match self.style() {
+            ProgressStyle::Hidden => ThrobberConfigBuilder::from(config)
+                .output(OutputMode::Hidden)
+                .build(),
+            ProgressStyle::Plain => ThrobberConfigBuilder::from(config)
+                .colors(None)
+                .color_choice(ColorChoice::Never)
+                .build(),
+            ProgressStyle::Colored => config,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Color math shared by the renderers
+
+use crossterm::style::Color;
+
+use crate::capabilities::ColorDepth;
+
+/// Approximate RGB value of a color, using the xterm palette for named colors
+pub(crate) fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let rgb = match color {
+        Color::Rgb { r, g, b } => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::DarkRed => (128, 0, 0),
+        Color::DarkGreen => (0, 128, 0),
+        Color::DarkYellow => (128, 128, 0),
+        Color::DarkBlue => (0, 0, 128),
+        Color::DarkMagenta => (128, 0, 128),
+        Color::DarkCyan => (0, 128, 128),
+        Color::Grey => (192, 192, 192),
+        Color::DarkGrey => (128, 128, 128),
+        Color::Red => (255, 0, 0),
+        Color::Green => (0, 255, 0),
+        Color::Yellow => (255, 255, 0),
+        Color::Blue => (0, 0, 255),
+        Color::Magenta => (255, 0, 255),
+        Color::Cyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::AnsiValue(value) => ansi_to_rgb(value),
+        Color::Reset => return None,
+    };
+    Some(rgb)
+}
+
+/// The 16 basic colors in ANSI order
+const BASIC: [Color; 16] = [
+    Color::Black,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::DarkYellow,
+    Color::DarkBlue,
+    Color::DarkMagenta,
+    Color::DarkCyan,
+    Color::Grey,
+    Color::DarkGrey,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+fn ansi_to_rgb(value: u8) -> (u8, u8, u8) {
+    match value {
+        0..=15 => to_rgb(BASIC[value as usize]).unwrap_or((0, 0, 0)),
+        16..=231 => {
+            // 6x6x6 color cube
+            let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+            let index = value - 16;
+            (level(index / 36), level((index / 6) % 6), level(index % 6))
+        }
+        _ => {
+            // 24-step grayscale ramp
+            let gray = 8 + (value - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Linear interpolation between two colors in RGB space, `t` in `0.0..=1.0`
+pub(crate) fn lerp(from: Color, to: Color, t: f64) -> Color {
+    let (Some(from), Some(to)) = (to_rgb(from), to_rgb(to)) else {
+        return if t < 0.5 { from } else { to };
+    };
+
+    let t = t.clamp(0.0, 1.0);
+    let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+    Color::Rgb {
+        r: channel(from.0, to.0),
+        g: channel(from.1, to.1),
+        b: channel(from.2, to.2),
+    }
+}
+
+/// The closest color a terminal limited to `depth` can show
+pub(crate) fn downsample(color: Color, depth: ColorDepth) -> Color {
+    match (depth, color) {
+        (ColorDepth::TrueColor, _) => color,
+        (ColorDepth::Ansi256, Color::Rgb { r, g, b }) => {
+            Color::AnsiValue(nearest_ansi256((r, g, b)))
+        }
+        (ColorDepth::Ansi16, Color::AnsiValue(value)) if value < 16 => BASIC[value as usize],
+        (ColorDepth::Ansi16, Color::Rgb { .. } | Color::AnsiValue(_)) => {
+            let rgb = to_rgb(color).unwrap_or((0, 0, 0));
+            BASIC
+                .into_iter()
+                .min_by_key(|&basic| distance(rgb, to_rgb(basic).unwrap_or((0, 0, 0))))
+                .unwrap_or(color)
+        }
+        // Named colors are in every palette
+        _ => color,
+    }
+}
+
+/// Index of the closest color in the 6x6x6 cube or the grayscale ramp
+fn nearest_ansi256((r, g, b): (u8, u8, u8)) -> u8 {
+    // Cube levels are 0, 95, 135, 175, 215 and 255; these are the midpoints
+    let level = |c: u8| match c {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (c - 35) / 40,
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    // Grays run from 8 to 238 in steps of 10
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    let rgb = (r, g, b);
+    if distance(rgb, ansi_to_rgb(gray)) < distance(rgb, ansi_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Squared distance between two colors in RGB space
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
@@ -0,0 +1,93 @@
+//! A ready-made bar for downloads and other byte transfers
+
+use std::{ops::Deref, sync::atomic::Ordering, time::Duration};
+
+use crate::{
+    format, Bar, BarConfig, BarConfigBuilder, BarCounters, BarMode, BarState, FinishReport, Units,
+};
+
+/// A byte bar showing the transfer rate and ETA, finished with a summary line
+///
+/// ```text
+/// [=======             ] 37% 448.0 MiB / 1.2 GiB 29.1 MiB/s ETA 27s ubuntu.iso
+/// [====================] 100% 1.2 GiB / 1.2 GiB avg 29.0 MiB/s ubuntu.iso: fetched 1.2 GiB in 42s
+/// ```
+///
+/// Derefs to the underlying [`Bar`], so `inc`, `handle` and the other bar
+/// methods report progress as usual.
+///
+/// ```rust
+/// use throbberous::DownloadBar;
+///
+/// # tokio_test::block_on(async {
+/// let download = DownloadBar::new("ubuntu.iso", 4 * 1024 * 1024);
+/// download.inc(4 * 1024 * 1024).await;
+/// download.finish().await;
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct DownloadBar {
+    bar: Bar,
+    label: String,
+}
+
+impl DownloadBar {
+    /// Creates a bar for a transfer of `total_bytes`, labelled e.g. with the URL or file name
+    pub fn new(label: impl Into<String>, total_bytes: u64) -> Self {
+        Self::with_config(label, total_bytes, BarConfig::default())
+    }
+
+    /// Like [`DownloadBar::new`], with rate and ETA turned on in `config`
+    ///
+    /// Plain count units are switched to binary byte units; decimal ones are kept.
+    pub fn with_config(label: impl Into<String>, total_bytes: u64, config: BarConfig) -> Self {
+        let label = label.into();
+        let units = match config.units {
+            Units::Count => Units::Bytes,
+            units => units,
+        };
+        let bar = Bar::spawn(
+            BarState::new(BarMode::Determinate, label.clone()),
+            BarCounters::new(total_bytes, true),
+            BarConfigBuilder::from(config)
+                .units(units)
+                .show_rate(true)
+                .show_eta(true)
+                .build(),
+        );
+        Self { bar, label }
+    }
+
+    /// Finishes the transfer, leaving `label: fetched 1.2 GiB in 42s` after the
+    /// average rate
+    pub async fn finish(&self) -> FinishReport {
+        let elapsed = self.bar.elapsed().await;
+        let bytes = self.bar.counters.total.load(Ordering::Relaxed);
+        let message = summary(&self.label, bytes, elapsed, &self.bar.config);
+        self.bar.finish_with_message(message).await
+    }
+}
+
+impl Deref for DownloadBar {
+    type Target = Bar;
+
+    fn deref(&self) -> &Bar {
+        &self.bar
+    }
+}
+
+/// `label: fetched <bytes> in <elapsed>`
+fn summary(label: &str, bytes: u64, elapsed: Duration, config: &BarConfig) -> String {
+    let summary = format!(
+        "{} {} {} {}",
+        config.strings.fetched,
+        format::bytes_with_precision(bytes as f64, config.units, config.unit_precision),
+        config.strings.fetched_in,
+        format::duration(elapsed)
+    );
+    if label.is_empty() {
+        summary
+    } else {
+        format!("{}: {}", label, summary)
+    }
+}
@@ -0,0 +1,139 @@
+//! Number, size and duration formatting shared by the renderers
+//!
+//! Exposed so applications can print their own figures the same way the bars do.
+
+use std::{borrow::Cow, time::Duration};
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Formats a count with thousands separators, e.g. `48,221`
+pub fn count(n: u64) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+
+    out
+}
+
+/// Formats a per-second rate compactly, e.g. `37`, `1.2k`, `4.5M`
+pub fn rate(per_sec: f64) -> String {
+    match per_sec {
+        r if r >= 1_000_000_000.0 => format!("{:.1}G", r / 1_000_000_000.0),
+        r if r >= 1_000_000.0 => format!("{:.1}M", r / 1_000_000.0),
+        r if r >= 1_000.0 => format!("{:.1}k", r / 1_000.0),
+        r if r >= 10.0 => format!("{:.0}", r),
+        r => format!("{:.1}", r),
+    }
+}
+
+/// Formats a duration for humans, e.g. `850ms`, `42s`, `2m 13s`, `1h 04m`, `3d 07h`
+///
+/// Only the two most significant units are shown; the rest is truncated.
+pub fn duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    match secs {
+        0 => format!("{}ms", d.as_millis()),
+        s if s < 60 => format!("{s}s"),
+        s if s < 3_600 => format!("{}m {:02}s", s / 60, s % 60),
+        s if s < 86_400 => format!("{}h {:02}m", s / 3_600, s % 3_600 / 60),
+        s => format!("{}d {:02}h", s / 86_400, s % 86_400 / 3_600),
+    }
+}
+
+/// How bar positions and rates are labelled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Units {
+    /// Plain counts; determinate bars show only the percentage
+    #[default]
+    Count,
+    /// Binary byte units: `12.4 MiB / 1.2 GiB`
+    Bytes,
+    /// Decimal byte units: `13.0 MB / 1.3 GB`
+    DecimalBytes,
+}
+
+/// Formats a byte amount with binary (`KiB`) or decimal (`kB`) units, e.g. `12.4 MiB`
+pub fn bytes(n: f64, units: Units) -> String {
+    bytes_with_precision(n, units, 1)
+}
+
+/// Like [`bytes`] with `precision` decimal places, e.g. `12.41 MiB` for 2
+///
+/// Whole bytes are always shown without decimals.
+pub fn bytes_with_precision(n: f64, units: Units, precision: usize) -> String {
+    let (base, suffixes) = match units {
+        Units::DecimalBytes => (1000.0, ["B", "kB", "MB", "GB", "TB", "PB", "EB"]),
+        _ => (1024.0, ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"]),
+    };
+
+    let mut value = n;
+    let mut unit = 0;
+    while value >= base && unit < suffixes.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{:.0} {}", value, suffixes[0])
+    } else {
+        format!("{:.*} {}", precision, value, suffixes[unit])
+    }
+}
+
+/// Which end of an over-long message is cut off
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Truncation {
+    /// Keep the beginning: `Downloading https://exa…`
+    #[default]
+    End,
+    /// Keep the end, useful for paths: `…/src/deeply/nested/file.rs`
+    Start,
+}
+
+/// Shortens text to at most `max_width` columns, marking the cut with `…`
+/// (or `...` when `unicode` is off)
+pub(crate) fn truncate(
+    text: &str,
+    max_width: usize,
+    truncation: Truncation,
+    unicode: bool,
+) -> Cow<'_, str> {
+    if text.width() <= max_width {
+        return Cow::Borrowed(text);
+    }
+
+    let ellipsis = if unicode { "…" } else { "..." };
+    if max_width < ellipsis.width() {
+        return Cow::Borrowed(&"..."[..max_width]);
+    }
+
+    // Reserve room for the ellipsis
+    let budget = max_width - ellipsis.width();
+    let mut used = 0;
+    let fits = |c: &char| {
+        used += c.width().unwrap_or(0);
+        used <= budget
+    };
+
+    Cow::Owned(match truncation {
+        Truncation::End => {
+            let kept: String = text.chars().take_while(fits).collect();
+            format!("{kept}{ellipsis}")
+        }
+        Truncation::Start => {
+            let mut kept: Vec<char> = text.chars().rev().take_while(fits).collect();
+            kept.reverse();
+            format!("{ellipsis}{}", kept.into_iter().collect::<String>())
+        }
+    })
+}
@@ -0,0 +1,161 @@
+//! Copying data while a widget shows how far along it is
+
+use std::path::Path;
+
+use tokio::{
+    fs::File,
+    io::{self, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+};
+
+use crate::{Bar, ProgressReporter, Status};
+
+/// Size of the buffer data is copied through, as in `tokio::io::copy`
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Copies all of `reader` into `writer` like [`tokio::io::copy`], advancing
+/// `progress` by the size of each chunk
+///
+/// Returns the number of bytes copied. The writer is flushed at the end; the
+/// reporter is left unfinished so it can cover more than one copy.
+pub async fn copy_with_progress<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    progress: &(impl ProgressReporter + ?Sized),
+) -> io::Result<u64>
+where
+    R: AsyncRead + Unpin + ?Sized,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let mut buf = vec![0; CHUNK_SIZE];
+    let mut copied = 0;
+    loop {
+        let read = reader.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        writer.write_all(&buf[..read]).await?;
+        copied += read as u64;
+        progress.inc(read as u64).await;
+    }
+    writer.flush().await?;
+    Ok(copied)
+}
+
+/// Copies the file at `src` to `dst` with a byte bar sized to the source file
+///
+/// The bar is labelled with the source's file name and finished once the
+/// copy completes, or marked failed with the error. Returns the number of
+/// bytes copied.
+pub async fn copy_file_with_progress(
+    src: impl AsRef<Path>,
+    dst: impl AsRef<Path>,
+) -> io::Result<u64> {
+    let src = src.as_ref();
+    let mut reader = File::open(src).await?;
+    let len = reader.metadata().await?.len();
+    let mut writer = File::create(dst).await?;
+
+    let bar = Bar::new_bytes(len);
+    let name = src.file_name().map_or_else(
+        || src.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    bar.set_message(name).await;
+
+    match copy_with_progress(&mut reader, &mut writer, &bar).await {
+        Ok(copied) => {
+            bar.finish().await;
+            Ok(copied)
+        }
+        Err(err) => {
+            bar.finish_with_status(Status::Error, err.to_string()).await;
+            Err(err)
+        }
+    }
+}
+
+/// Reads lines from an [`AsyncBufRead`], advancing a reporter by one per line
+///
+/// Works like [`tokio::io::Lines`]: the returned lines have their `\n` or
+/// `\r\n` stripped. Size the bar with a known line count, or with
+/// [`count_lines`] when reading a file.
+///
+/// ```rust,no_run
+/// use throbberous::{io::{count_lines, ProgressLines}, Bar};
+/// use tokio::{fs::File, io::BufReader};
+/// # tokio_test::block_on(async {
+/// let bar = Bar::new(count_lines("access.log").await?);
+/// let file = BufReader::new(File::open("access.log").await?);
+/// let mut lines = ProgressLines::new(file, &bar);
+/// while let Some(line) = lines.next_line().await? {
+///     // ...
+/// #   let _ = line;
+/// }
+/// bar.finish().await;
+/// # Ok::<_, std::io::Error>(())
+/// # });
+/// ```
+pub struct ProgressLines<R, P> {
+    reader: R,
+    progress: P,
+    buf: String,
+}
+
+impl<R, P> ProgressLines<R, P>
+where
+    R: AsyncBufRead + Unpin,
+    P: ProgressReporter,
+{
+    /// Wraps `reader`; `progress` is usually `&bar`
+    pub fn new(reader: R, progress: P) -> Self {
+        Self {
+            reader,
+            progress,
+            buf: String::new(),
+        }
+    }
+
+    /// The next line, or `None` at the end of the input
+    pub async fn next_line(&mut self) -> io::Result<Option<String>> {
+        self.buf.clear();
+        if self.reader.read_line(&mut self.buf).await? == 0 {
+            return Ok(None);
+        }
+        if self.buf.ends_with('\n') {
+            self.buf.pop();
+            if self.buf.ends_with('\r') {
+                self.buf.pop();
+            }
+        }
+        self.progress.inc(1).await;
+        Ok(Some(self.buf.clone()))
+    }
+
+    /// The wrapped reader, positioned after the last line read
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// Number of lines in the file at `path`, for sizing a bar before reading it
+///
+/// A last line without a trailing newline is counted too, matching what
+/// [`ProgressLines`] yields.
+pub async fn count_lines(path: impl AsRef<Path>) -> io::Result<u64> {
+    let mut file = File::open(path).await?;
+    let mut buf = vec![0; CHUNK_SIZE];
+    let mut lines = 0;
+    let mut last = b'\n';
+    loop {
+        let read = file.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        lines += buf[..read].iter().filter(|&&byte| byte == b'\n').count() as u64;
+        last = buf[read - 1];
+    }
+    if last != b'\n' {
+        lines += 1;
+    }
+    Ok(lines)
+}
@@ -30,26 +30,1123 @@
 //! });
 //! ```
 
+mod alert;
+mod animation;
+mod builder;
+mod cancel;
+pub mod capabilities;
+#[cfg(feature = "clap")]
+mod cli;
+mod color;
+mod download;
+pub mod format;
+pub mod io;
+#[cfg(feature = "log-bridge")]
+mod log_bridge;
+#[cfg(feature = "mirror")]
+pub mod mirror;
+mod multi;
+pub mod presets;
+mod queue;
+mod registry;
+mod render;
+mod reporter;
+mod screen;
+mod session;
+mod steps;
+#[cfg(feature = "stream")]
+mod stream;
+mod strings;
+mod summary;
+#[cfg(feature = "test-util")]
+mod test_renderer;
+mod theme;
+mod ticker;
+#[cfg(feature = "ratatui")]
+mod tui;
+mod validate;
+
+pub use alert::FinishAlert;
+pub use animation::{shared_animator, Animator, IndeterminateStyle, SharedAnimator};
+pub use builder::{BarConfigBuilder, ThrobberBuilder, ThrobberConfigBuilder};
+pub use cancel::CancelAction;
+pub use capabilities::{Charset, ColorChoice, ColorDepth};
+#[cfg(feature = "clap")]
+pub use cli::{ProgressArgs, ProgressStyle};
+pub use download::DownloadBar;
+pub use format::{Truncation, Units};
+#[cfg(feature = "log-bridge")]
+pub use log_bridge::LogBridge;
+pub use multi::MultiBar;
+pub use queue::{QueueReport, TaskOutcome, TaskQueue};
+pub use registry::{active_widgets, set_conflict_hook, WidgetConflict};
+pub use render::{DrawTarget, LineProgress, OutputMode, Renderer, RendererFactory, Segment};
+pub use reporter::{NoopReporter, ProgressReporter, ReportFuture};
+pub use screen::{println, suspend};
+pub use session::{init, install_panic_hook, Session};
+pub use steps::Steps;
+#[cfg(feature = "stream")]
+pub use stream::ProgressStream;
+pub use strings::Strings;
+pub use summary::{collect_summary, summary, Summary, SummaryTable, WidgetSummary};
+#[cfg(feature = "test-util")]
+pub use test_renderer::TestRenderer;
+pub use theme::{TextStyle, Theme};
+#[cfg(feature = "cancellation")]
+pub use tokio_util::sync::CancellationToken;
+#[cfg(feature = "ratatui")]
+pub use tui::TuiFrame;
+pub use validate::ConfigError;
+
 use crossterm::{
-    cursor::MoveToColumn,
-    execute,
-    style::{Color, Print, ResetColor, SetForegroundColor},
-    terminal::{Clear, ClearType},
+    cursor::{Hide, MoveTo, MoveToColumn, MoveUp, RestorePosition, SavePosition, Show},
+    queue,
+    style::{
+        Attribute, Color, Print, ResetColor, SetAttribute, SetAttributes, SetBackgroundColor,
+        SetForegroundColor,
+    },
+    terminal::{Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::{
+    borrow::Cow,
+    fmt,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex as StdMutex, OnceLock,
+    },
+    time::{Duration, Instant},
 };
-use std::{io, sync::Arc, time::Duration};
 use tokio::{
-    sync::{Mutex, Notify},
-    task::{self, JoinHandle},
-    time::sleep,
+    sync::{watch, Mutex, Notify},
+    task::{self, JoinHandle, JoinSet},
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use render::{AccessibleRenderer, AppendRenderer, Frame, HiddenRenderer, JsonRenderer};
+use screen::FrameUpdate;
+
+// --- Terminal Output ---
+
+/// Callback invoked once when a widget gives up writing to the terminal
+pub type ErrorHook = Arc<dyn Fn(&std::io::Error) + Send + Sync>;
+
+/// Builds a bar's whole line from its snapshot, see `BarConfig::formatter`
+pub type LineFormatter = Arc<dyn Fn(&ProgressSnapshot) -> String + Send + Sync>;
+
+/// Message shown for a fraction of progress, see `BarConfig::message_provider`
+pub type MessageProvider = Arc<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Consecutive write failures tolerated before a terminal is considered gone
+const MAX_WRITE_FAILURES: u32 = 3;
+
+/// Where a terminal draws live frames, see `BarConfig::pin_to_bottom` and
+/// `BarConfig::alternate_screen`
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    /// Overwriting the previous frame where the cursor is
+    Inline,
+    /// On the bottom rows, below a scroll region for other output
+    PinnedToBottom,
+    /// Filling the alternate screen from the top
+    AlternateScreen,
+}
+
+/// A widget's output stream that goes silent once writes keep failing
+///
+/// With `hide_cursor` the cursor is hidden by the first write and shown
+/// again by [`Terminal::show_cursor`] or, at the latest, on drop.
+struct Terminal {
+    target: DrawTarget,
+    colors: bool,
+    /// Colors beyond it are drawn as the nearest one the terminal has
+    depth: ColorDepth,
+    hide_cursor: bool,
+    cursor_hidden: bool,
+    /// Lines taken by the last rewound block, so a shorter block can clear the rest
+    block_height: usize,
+    /// The live block as last drawn, empty unless it is still on screen as it
+    /// was at `version`, so the next frame only has to redraw what changed
+    drawn: Vec<Vec<Segment>>,
+    /// Screen version after this terminal's latest write
+    version: u64,
+    /// Where on the screen live frames go
+    layout: Layout,
+    /// Rows taken by the pinned block, 0 while no scroll region is set
+    pinned_height: usize,
+    /// Terminal height the scroll region was set up for
+    pinned_rows: usize,
+    /// Whether live frames are currently drawn on the alternate screen
+    alternate: bool,
+    failures: u32,
+    silent: bool,
+    on_error: Option<ErrorHook>,
+}
+
+impl Terminal {
+    fn new(
+        target: DrawTarget,
+        color_choice: ColorChoice,
+        hide_cursor: bool,
+        layout: Layout,
+        on_error: Option<ErrorHook>,
+    ) -> Self {
+        Self {
+            target,
+            colors: color_choice.enabled_for(target),
+            depth: capabilities::color_depth(),
+            hide_cursor,
+            cursor_hidden: false,
+            block_height: 0,
+            drawn: Vec::new(),
+            version: 0,
+            layout,
+            pinned_height: 0,
+            pinned_rows: 0,
+            alternate: false,
+            failures: 0,
+            silent: false,
+            on_error,
+        }
+    }
+
+    /// Writes several lines with a single write + flush
+    ///
+    /// With `rewind` the cursor goes back to the first line afterwards so the
+    /// next frame overwrites the whole block; otherwise it stays on the last line.
+    /// Lines left over from a taller previous block are cleared.
+    fn write_lines(&mut self, lines: &[Vec<Segment>], rewind: bool) {
+        match self.layout {
+            Layout::Inline => {}
+            Layout::PinnedToBottom => {
+                let rows = capabilities::terminal_height();
+                // Leave at least one row for the output scrolling above
+                if let Some(rows) =
+                    rows.filter(|&rows| rewind && !lines.is_empty() && lines.len() < rows)
+                {
+                    self.drawn.clear();
+                    self.write_pinned(lines, rows);
+                    return;
+                }
+                self.unpin();
+            }
+            Layout::AlternateScreen => {
+                let rows = capabilities::terminal_height();
+                if let Some(rows) = rows.filter(|_| rewind && !lines.is_empty()) {
+                    self.drawn.clear();
+                    self.write_alternate(lines, rows);
+                    return;
+                }
+                // The final frame goes back to the regular screen, below what was there
+                self.leave_alternate_screen();
+            }
+        }
+
+        let live = rewind && !lines.is_empty();
+        let changes = (live && !self.drawn.is_empty()).then(|| self.changes(lines));
+
+        let len = lines
+            .iter()
+            .map(|line| Self::segments_capacity(line))
+            .sum::<usize>();
+        let mut buf = Vec::with_capacity(len + 8);
+
+        let height = lines.len().max(self.block_height);
+        for i in 0..height {
+            if i > 0 {
+                let _ = queue!(buf, Print("\n"));
+            }
+            match lines.get(i) {
+                Some(line) => self.queue_segments(&mut buf, line),
+                None => {
+                    let _ = queue!(buf, MoveToColumn(0), Clear(ClearType::CurrentLine));
+                }
+            }
+        }
+
+        let up = if rewind {
+            height.saturating_sub(1)
+        } else {
+            height - lines.len()
+        };
+        if up > 0 {
+            let _ = queue!(buf, MoveUp(up as u16), MoveToColumn(0));
+        }
+        self.block_height = if rewind { lines.len() } else { 0 };
+
+        let written = match changes {
+            Some(changes) => self.write_raw(
+                &changes,
+                FrameUpdate::Changes {
+                    frame: &buf,
+                    since: self.version,
+                },
+            ),
+            None if live => self.write(&buf, FrameUpdate::Live),
+            None => self.write(&buf, FrameUpdate::Done),
+        };
+        self.drawn.clear();
+        if live && written {
+            self.drawn.extend_from_slice(lines);
+        }
+    }
+
+    /// Like a whole frame of `lines`, but skipping what is unchanged since the
+    /// block was drawn last
+    ///
+    /// Unchanged lines are stepped over, changed ones redrawn from the first
+    /// column that differs. Empty if nothing changed.
+    fn changes(&self, lines: &[Vec<Segment>]) -> Vec<u8> {
+        let height = lines.len().max(self.block_height);
+        let mut buf = Vec::new();
+        // Line the cursor is on
+        let mut cursor = 0;
+
+        for i in 0..height {
+            let previous = self.drawn.get(i);
+            let line = lines.get(i);
+            if line.is_some() && line == previous {
+                continue;
+            }
+
+            if i > cursor {
+                let _ = queue!(buf, Print("\n".repeat(i - cursor)));
+                cursor = i;
+            }
+            match (line, previous) {
+                (Some(line), Some(previous)) => self.queue_changed(&mut buf, previous, line),
+                (Some(line), None) => self.queue_segments(&mut buf, line),
+                (None, _) => {
+                    let _ = queue!(buf, MoveToColumn(0), Clear(ClearType::CurrentLine));
+                }
+            }
+        }
+
+        if buf.is_empty() {
+            return buf;
+        }
+        // Back to the first line, like a whole frame
+        if cursor > 0 {
+            let _ = queue!(buf, MoveUp(cursor as u16), MoveToColumn(0));
+        }
+        buf
+    }
+
+    /// Redraws `line` over `previous` from the first column where they differ
+    fn queue_changed(&self, buf: &mut Vec<u8>, previous: &[Segment], line: &[Segment]) {
+        let mut column = 0;
+        let mut start = (previous.len().min(line.len()), 0);
+        for (index, (old, new)) in previous.iter().zip(line).enumerate() {
+            if old == new {
+                column += text_width(&new.text);
+                continue;
+            }
+            // Text shared at the start of a segment drawn the same way stays too
+            let shared = if old.color == new.color && old.style == new.style {
+                old.text
+                    .char_indices()
+                    .zip(new.text.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(old.text.len().min(new.text.len()), |((offset, _), _)| {
+                        offset
+                    })
+            } else {
+                0
+            };
+            column += text_width(&new.text[..shared]);
+            start = (index, shared);
+            break;
+        }
+
+        let _ = queue!(
+            buf,
+            MoveToColumn(column as u16),
+            Clear(ClearType::UntilNewLine)
+        );
+        let (index, offset) = start;
+        for (i, segment) in line.iter().enumerate().skip(index) {
+            let text = if i == index {
+                &segment.text[offset..]
+            } else {
+                &segment.text
+            };
+            self.queue_segment(buf, segment, text);
+        }
+    }
+
+    /// Draws `lines` on the bottom rows of a `rows` high terminal
+    ///
+    /// The rows above become a scroll region, so output printed meanwhile
+    /// scrolls there instead of running into the block.
+    fn write_pinned(&mut self, lines: &[Vec<Segment>], rows: usize) {
+        let height = lines.len();
+        let mut buf = Vec::new();
+
+        if height != self.pinned_height || rows != self.pinned_rows {
+            // Scroll the output up so the rows the block takes over are free
+            let grow = height.saturating_sub(self.pinned_height);
+            if grow > 0 {
+                let _ = queue!(buf, Print("\n".repeat(grow)), MoveUp(grow as u16));
+            }
+            // Setting the region homes the cursor, so it is saved around it
+            let _ = queue!(
+                buf,
+                SavePosition,
+                Print(format!("\x1b[1;{}r", rows - height)),
+                RestorePosition
+            );
+            screen::set_scroll_region(true);
+        }
+
+        // Rows a taller previous block used are cleared too
+        let top = rows - height.max(self.pinned_height).min(rows - 1);
+        let _ = queue!(buf, SavePosition);
+        for row in top..rows {
+            let _ = queue!(buf, MoveTo(0, row as u16));
+            match (row + height).checked_sub(rows).and_then(|i| lines.get(i)) {
+                Some(line) => self.queue_segments(&mut buf, line),
+                None => {
+                    let _ = queue!(buf, Clear(ClearType::CurrentLine));
+                }
+            }
+        }
+        let _ = queue!(buf, RestorePosition);
+
+        self.pinned_height = height;
+        self.pinned_rows = rows;
+        self.write(&buf, FrameUpdate::Done);
+    }
+
+    /// Clears the pinned block and gives its rows back to regular output
+    fn unpin(&mut self) {
+        if self.pinned_height == 0 {
+            return;
+        }
+
+        let rows = self.pinned_rows;
+        let mut buf = Vec::new();
+        let _ = queue!(buf, SavePosition);
+        for row in rows - self.pinned_height..rows {
+            let _ = queue!(buf, MoveTo(0, row as u16), Clear(ClearType::CurrentLine));
+        }
+        let _ = queue!(buf, Print("\x1b[r"), RestorePosition);
+
+        self.pinned_height = 0;
+        self.write_raw(&buf, FrameUpdate::Keep);
+        screen::set_scroll_region(false);
+    }
+
+    /// Draws `lines` from the top of the alternate screen, switching to it first
+    ///
+    /// The whole screen is redrawn every frame; lines beyond the terminal's
+    /// `rows` are cut off.
+    fn write_alternate(&mut self, lines: &[Vec<Segment>], rows: usize) {
+        let mut buf = Vec::new();
+        if !self.alternate {
+            let _ = queue!(buf, EnterAlternateScreen);
+            screen::set_alternate_screen(true);
+            self.alternate = true;
+        }
+
+        let shown = lines.len().min(rows);
+        for (row, line) in lines[..shown].iter().enumerate() {
+            let _ = queue!(buf, MoveTo(0, row as u16));
+            self.queue_segments(&mut buf, line);
+        }
+        if shown < rows {
+            let _ = queue!(
+                buf,
+                MoveTo(0, shown as u16),
+                Clear(ClearType::FromCursorDown)
+            );
+        }
+        self.write(&buf, FrameUpdate::Done);
+    }
+
+    /// Switches back to the regular screen, as it was before the first frame
+    fn leave_alternate_screen(&mut self) {
+        if !self.alternate {
+            return;
+        }
+
+        let mut buf = Vec::new();
+        let _ = queue!(buf, LeaveAlternateScreen);
+        self.alternate = false;
+        self.write_raw(&buf, FrameUpdate::Keep);
+        screen::set_alternate_screen(false);
+    }
+
+    fn queue_segments(&self, buf: &mut Vec<u8>, segments: &[Segment]) {
+        let _ = queue!(buf, MoveToColumn(0), Clear(ClearType::CurrentLine));
+        for segment in segments {
+            self.queue_segment(buf, segment, &segment.text);
+        }
+    }
+
+    /// Queues `text` drawn the way `segment` is
+    fn queue_segment(&self, buf: &mut Vec<u8>, segment: &Segment, text: &str) {
+        let color = segment
+            .color
+            .filter(|_| self.colors)
+            .map(|color| self.depth.downsample(color));
+        if self.colors && !segment.style.is_plain() {
+            if let Some(color) = color {
+                let _ = queue!(buf, SetForegroundColor(color));
+            }
+            if let Some(background) = segment.style.background {
+                let _ = queue!(buf, SetBackgroundColor(self.depth.downsample(background)));
+            }
+            let _ = queue!(
+                buf,
+                SetAttributes(segment.style.attributes()),
+                Print(text),
+                SetAttribute(Attribute::Reset)
+            );
+            return;
+        }
+
+        let _ = match color {
+            Some(color) => queue!(buf, SetForegroundColor(color), Print(text), ResetColor),
+            None => queue!(buf, Print(text)),
+        };
+    }
+
+    fn segments_capacity(segments: &[Segment]) -> usize {
+        let len = segments
+            .iter()
+            .map(|segment| segment.text.len())
+            .sum::<usize>();
+        len + 16 * segments.len() + 16
+    }
+
+    /// Shows the cursor again if a previous write hid it
+    fn show_cursor(&mut self) {
+        if self.cursor_hidden {
+            self.cursor_hidden = false;
+            let mut buf = Vec::new();
+            let _ = queue!(buf, Show);
+            self.write_raw(&buf, FrameUpdate::Keep);
+        }
+    }
+
+    /// Writes `buf`, hiding the cursor first if it should be; returns whether it was written
+    fn write(&mut self, buf: &[u8], update: FrameUpdate) -> bool {
+        if self.hide_cursor && !self.cursor_hidden && !self.silent {
+            let mut framed = Vec::with_capacity(buf.len() + 8);
+            let _ = queue!(framed, Hide);
+            framed.extend_from_slice(buf);
+            self.cursor_hidden = self.write_raw(&framed, update);
+            return self.cursor_hidden;
+        }
+
+        self.write_raw(buf, update)
+    }
+
+    /// Writes and flushes `buf`, returning whether it reached the terminal
+    fn write_raw(&mut self, buf: &[u8], update: FrameUpdate) -> bool {
+        if self.silent {
+            return false;
+        }
+
+        let result = screen::write(self.target, buf, update);
+
+        match result {
+            Ok(version) => {
+                self.version = version;
+                self.failures = 0;
+                true
+            }
+            Err(err) => {
+                self.failures += 1;
+
+                // A closed pipe never recovers, anything else gets a few retries
+                if err.kind() == std::io::ErrorKind::BrokenPipe
+                    || self.failures >= MAX_WRITE_FAILURES
+                {
+                    self.silent = true;
+                    if let Some(ref on_error) = self.on_error {
+                        on_error(&err);
+                    }
+                }
+                false
+            }
+        }
+    }
+}
+
+impl Renderer for Terminal {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.write_lines(lines, true);
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.write_lines(lines, false);
+        if !lines.is_empty() {
+            self.write(b"\n", FrameUpdate::Done);
+        }
+        self.show_cursor();
+    }
+
+    fn clear(&mut self) {
+        self.write_lines(&[], true);
+        self.show_cursor();
+    }
+}
+
+impl Drop for Terminal {
+    // Covers widgets that are dropped or whose task is aborted mid-frame
+    fn drop(&mut self) {
+        self.unpin();
+        self.leave_alternate_screen();
+        self.show_cursor();
+    }
+}
+
+/// The renderer a widget draws with: the configured one, or one for its output mode
+#[allow(clippy::too_many_arguments)]
+fn renderer(
+    factory: Option<&RendererFactory>,
+    output: OutputMode,
+    target: DrawTarget,
+    color_choice: ColorChoice,
+    hide_cursor: bool,
+    layout: Layout,
+    on_error: Option<ErrorHook>,
+    strings: &Strings,
+) -> Box<dyn Renderer> {
+    if let Some(factory) = factory {
+        return factory();
+    }
+    match output.resolve_for(target) {
+        OutputMode::Json => Box::new(JsonRenderer::new(target)),
+        OutputMode::Append => Box::new(AppendRenderer::new(target)),
+        OutputMode::Accessible => Box::new(AccessibleRenderer::new(target, strings.clone())),
+        OutputMode::Hidden => Box::new(HiddenRenderer),
+        OutputMode::Auto | OutputMode::Terminal => Box::new(Terminal::new(
+            target,
+            color_choice,
+            hide_cursor,
+            layout,
+            on_error,
+        )),
+    }
+}
+
+/// Display width of text in terminal columns
+fn text_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Truncates text so a line with `used` columns already taken fits within `columns`
+fn fit_text(
+    text: &str,
+    columns: Option<usize>,
+    used: usize,
+    truncation: Truncation,
+    charset: Charset,
+) -> Cow<'_, str> {
+    match columns {
+        // Leave the last column free so the cursor never wraps
+        Some(columns) => format::truncate(
+            text,
+            columns.saturating_sub(used + 1),
+            truncation,
+            charset.unicode(),
+        ),
+        None => Cow::Borrowed(text),
+    }
+}
+
+/// Locks a bar tree's child list, recovering it if a previous holder panicked
+fn lock_children(children: &StdMutex<Vec<ChildLine>>) -> std::sync::MutexGuard<'_, Vec<ChildLine>> {
+    children
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Locks a shared renderer, recovering it if a previous holder panicked
+fn lock_renderer(
+    renderer: &StdMutex<Box<dyn Renderer>>,
+) -> std::sync::MutexGuard<'_, Box<dyn Renderer>> {
+    renderer
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// --- Render Stats ---
+
+/// Rendering performance counters for a widget, see [`Bar::render_stats`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    /// Frames actually written to the terminal
+    pub frames_drawn: u64,
+    /// Redraw requests that were coalesced into another frame
+    pub frames_skipped: u64,
+    /// Average time spent building and writing a frame
+    pub avg_draw_micros: f64,
+}
+
+/// Wakes a draw task and counts how many redraws were requested vs performed
+struct RenderSignal {
+    notify: Notify,
+    /// Held by the draw task while it draws a frame, and by [`Bar::suspend`]
+    drawing: Mutex<()>,
+    requested: AtomicU64,
+    drawn: AtomicU64,
+    draw_nanos: AtomicU64,
+}
+
+impl RenderSignal {
+    fn new() -> Self {
+        Self {
+            notify: Notify::new(),
+            drawing: Mutex::new(()),
+            requested: AtomicU64::new(0),
+            drawn: AtomicU64::new(0),
+            draw_nanos: AtomicU64::new(0),
+        }
+    }
+
+    fn notify_one(&self) {
+        self.requested.fetch_add(1, Ordering::Relaxed);
+        self.notify.notify_one();
+    }
+
+    async fn notified(&self) {
+        self.notify.notified().await;
+    }
+
+    /// Waits for a frame being drawn to be done; no other starts until the guard is dropped
+    async fn drawing(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.drawing.lock().await
+    }
+
+    fn record_draw(&self, started: Instant) {
+        let nanos = started.elapsed().as_nanos().min(u64::MAX as u128) as u64;
+        self.drawn.fetch_add(1, Ordering::Relaxed);
+        self.draw_nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> RenderStats {
+        let requested = self.requested.load(Ordering::Relaxed);
+        let drawn = self.drawn.load(Ordering::Relaxed);
+        let draw_nanos = self.draw_nanos.load(Ordering::Relaxed);
+
+        RenderStats {
+            frames_drawn: drawn,
+            frames_skipped: requested.saturating_sub(drawn),
+            avg_draw_micros: if drawn == 0 {
+                0.0
+            } else {
+                draw_nanos as f64 / drawn as f64 / 1_000.0
+            },
+        }
+    }
+}
+
+// --- Status Theme ---
+
+/// Outcome a widget finished with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    Success,
+    Warning,
+    Error,
+    Cancelled,
+    Timeout,
+}
+
+/// Symbol and color a finished widget is drawn with
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusStyle {
+    pub symbol: String,
+    pub color: Option<Color>, // None = no colors
+}
+
+impl StatusStyle {
+    pub fn new(symbol: impl Into<String>, color: Option<Color>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            color,
+        }
+    }
+}
+
+/// Outcome styling shared by the finish methods of [`Bar`] and [`Throbber`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StatusTheme {
+    pub success: StatusStyle,
+    pub warning: StatusStyle,
+    pub error: StatusStyle,
+    pub cancelled: StatusStyle,
+    pub timeout: StatusStyle,
+}
+
+impl Default for StatusTheme {
+    /// Unicode symbols, or their ASCII fallbacks when the terminal isn't UTF-8
+    fn default() -> Self {
+        if capabilities::supports_unicode() {
+            Self::unicode()
+        } else {
+            Self::ascii()
+        }
+    }
+}
+
+impl StatusTheme {
+    /// `✓` `⚠` `✗` `⊘` `⧗`
+    pub fn unicode() -> Self {
+        Self {
+            success: StatusStyle::new("✓", Some(Color::Green)),
+            warning: StatusStyle::new("⚠", Some(Color::Yellow)),
+            error: StatusStyle::new("✗", Some(Color::Red)),
+            cancelled: StatusStyle::new("⊘", Some(Color::DarkGrey)),
+            timeout: StatusStyle::new("⧗", Some(Color::Magenta)),
+        }
+    }
+
+    /// `OK` `WARN` `FAIL` `CANCELLED` `TIMEOUT`, safe for any charset
+    pub fn ascii() -> Self {
+        Self {
+            success: StatusStyle::new("OK", Some(Color::Green)),
+            warning: StatusStyle::new("WARN", Some(Color::Yellow)),
+            error: StatusStyle::new("FAIL", Some(Color::Red)),
+            cancelled: StatusStyle::new("CANCELLED", Some(Color::DarkGrey)),
+            timeout: StatusStyle::new("TIMEOUT", Some(Color::Magenta)),
+        }
+    }
+
+    /// The style used for a given outcome
+    pub fn style(&self, status: Status) -> &StatusStyle {
+        match status {
+            Status::Success => &self.success,
+            Status::Warning => &self.warning,
+            Status::Error => &self.error,
+            Status::Cancelled => &self.cancelled,
+            Status::Timeout => &self.timeout,
+        }
+    }
+}
+
+// --- Deadlines ---
+
+/// Soft and hard time limits for throbbers and indeterminate bars
+///
+/// Checked on every animation tick, so they apply even when nothing else
+/// updates the widget. Time is counted from `start` for a throbber and from
+/// creation for a bar.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Deadline {
+    /// After this long the widget is considered slow
+    pub soft: Option<Duration>,
+    /// Message shown once the soft deadline passes; `None` keeps the current one
+    pub slow_message: Option<String>,
+    /// Color the whole line is drawn in once the soft deadline passes
+    pub slow_color: Option<Color>,
+    /// After this long the widget stops on its own with [`Status::Timeout`]
+    pub hard: Option<Duration>,
+}
+
+/// Where a widget stands relative to its [`Deadline`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lateness {
+    OnTime,
+    Slow,
+    TimedOut,
+}
+
+impl Deadline {
+    fn check(&self, elapsed: Duration) -> Lateness {
+        if self.hard.is_some_and(|hard| elapsed >= hard) {
+            Lateness::TimedOut
+        } else if self.soft.is_some_and(|soft| elapsed >= soft) {
+            Lateness::Slow
+        } else {
+            Lateness::OnTime
+        }
+    }
+
+    /// Line color while slow, if the widget is past its soft deadline
+    fn slow_color(deadline: Option<&Deadline>, slow: bool) -> Option<Color> {
+        deadline
+            .filter(|_| slow)
+            .and_then(|deadline| deadline.slow_color)
+    }
+}
 
 // --- Progress Bar Implementation ---
 
+/// Whether a determinate bar shows its position as `37/120` next to the percentage
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CountDisplay {
+    /// Percentage only (byte units always show their position)
+    #[default]
+    Hidden,
+    /// `42% 37/120 files`
+    WithPercent,
+    /// `37/120 files`, no percentage
+    InsteadOfPercent,
+}
+
+/// How a determinate bar with a total of 0 is drawn, as there is no fraction to show
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ZeroTotal {
+    /// Finished at 100% as soon as it is created, there being nothing to do
+    #[default]
+    Complete,
+    /// Indeterminate until [`Bar::set_total`] gives it a size
+    Indeterminate,
+}
+
+/// How a bar picks its color on each frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ColorMode {
+    /// Step through `BarConfig::colors`
+    Cycle,
+    /// Interpolate in RGB from `from` at 0% to `to` at 100% (determinate bars only,
+    /// other bars stay on `from`)
+    Gradient { from: Color, to: Color },
+}
+
+impl ColorMode {
+    /// Red at 0% to green at 100%
+    pub fn red_to_green() -> Self {
+        ColorMode::Gradient {
+            from: Color::Rgb {
+                r: 220,
+                g: 50,
+                b: 47,
+            },
+            to: Color::Rgb {
+                r: 80,
+                g: 200,
+                b: 60,
+            },
+        }
+    }
+}
+
+/// A named stretch of a determinate bar's progress, see `BarConfig::phases`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Phase {
+    pub name: String,
+    /// Share of the total, relative to the other phases' weights
+    pub weight: f64,
+    /// Bar color while the phase is current; `None` keeps the cycle or gradient color
+    pub color: Option<Color>,
+}
+
+impl Phase {
+    pub fn new(name: impl Into<String>, weight: f64, color: Option<Color>) -> Self {
+        Self {
+            name: name.into(),
+            weight,
+            color,
+        }
+    }
+
+    /// The phase `progress` (0.0 to 1.0) falls into
+    ///
+    /// Phases without a positive weight are never current, unless none has one,
+    /// in which case they split the bar evenly.
+    fn current(phases: &[Phase], progress: f64) -> Option<&Phase> {
+        let weight = |phase: &Phase| phase.weight.max(0.0);
+        let sum: f64 = phases.iter().map(weight).sum();
+        let mut end = 0.0;
+        for phase in phases {
+            end += if sum > 0.0 {
+                weight(phase) / sum
+            } else {
+                1.0 / phases.len() as f64
+            };
+            if progress < end {
+                return Some(phase);
+            }
+        }
+        phases
+            .iter()
+            .rev()
+            .find(|phase| sum <= 0.0 || weight(phase) > 0.0)
+    }
+}
+
+/// Narrowest a bar is shrunk to when fitting it into the terminal
+const MIN_FIT_WIDTH: usize = 10;
+
+/// Spinner shown in front of counting bars
+const COUNTING_FRAMES: &[&str] = presets::DOTS;
+
+/// [`COUNTING_FRAMES`] for terminals without braille
+const ASCII_COUNTING_FRAMES: &[&str] = presets::LINE;
+
+/// How long each frame of `BarConfig::spinner` is shown, in milliseconds
+const SPINNER_FRAME_MS: u128 = 100;
+
+/// The `BarConfig::spinner` frame shown right now, the same for every bar
+fn spinner_frame(frames: &[String], charset: Charset) -> &str {
+    static CLOCK: OnceLock<Instant> = OnceLock::new();
+    let step = CLOCK.get_or_init(Instant::now).elapsed().as_millis() / SPINNER_FRAME_MS;
+
+    let frame = match frames.len() {
+        0 => "",
+        len => &frames[(step % len as u128) as usize],
+    };
+    if frame.is_ascii() || charset.unicode() {
+        frame
+    } else {
+        presets::LINE[(step % presets::LINE.len() as u128) as usize]
+    }
+}
+
+/// How a [`Bar`] looks and behaves
+///
+/// Fields are added over time, so configs are made with
+/// [`BarConfig::builder`] or a preset such as [`BarConfig::no_colors`]
+/// rather than struct literals.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
 pub struct BarConfig {
     pub colors: Option<Vec<Color>>, // None = no colors
+    /// Whether colors are emitted at all; `Auto` honors `NO_COLOR` / `CLICOLOR_FORCE`
+    pub color_choice: ColorChoice,
+    /// Milliseconds each color of the cycle is shown for, however often the bar
+    /// is redrawn; 0 keeps the first color
     pub color_cycle_delay: u64,
+    pub color_mode: ColorMode,
+    /// Per-part colors; parts left unset follow the color cycle or gradient
+    pub theme: Theme,
+    /// Bar width in columns; an upper bound when `fit_to_terminal` is set
     pub width: usize,
+    /// Shrink the bar (and then truncate the message) so the line fits the terminal
+    pub fit_to_terminal: bool,
+    /// Which end of a message too long for the terminal is cut off
+    pub truncation: Truncation,
+    /// Show position, total and rate as byte sizes instead of plain counts
+    pub units: Units,
+    /// Show `current/total` next to or instead of the percentage
+    pub count_display: CountDisplay,
+    /// Finish or animate a bar created with a total of 0
+    pub zero_total: ZeroTotal,
+    /// Show the percentage of determinate bars; `CountDisplay::InsteadOfPercent`
+    /// hides it as well
+    pub show_percent: bool,
+    /// Decimal places of the percentage, e.g. `1` for `42.3%`
+    pub percent_precision: usize,
+    /// Unit label appended to the count, e.g. `files`
+    pub count_label: Option<String>,
+    /// Show the throughput of determinate bars while they run, e.g. `29.0 MiB/s`,
+    /// and the average over the whole run once finished, e.g. `avg 27.3 MiB/s`
+    pub show_rate: bool,
+    /// Show the estimated time left on determinate bars while they run, e.g. `ETA 12s`
+    pub show_eta: bool,
+    /// Weight (0.0 to 1.0) of each new one-second throughput sample in the rate
+    /// and ETA; lower is steadier, `1.0` shows the latest second unsmoothed
+    pub rate_smoothing: f64,
+    /// Decimal places of byte sizes and rates once scaled past bytes, e.g. `2`
+    /// for `12.41 MiB/s`
+    pub unit_precision: usize,
+    /// Character for the completed part of the bar (and the indeterminate block)
+    pub filled_char: char,
+    /// Character for the remaining part of the bar
+    pub empty_char: char,
+    /// Optional character drawn at the leading edge of the filled part, e.g. `>` for `==>`;
+    /// may be two columns wide, like `🚀`
+    pub head_char: Option<char>,
+    /// Named phases splitting a determinate bar by weight, e.g. download, verify,
+    /// extract; the current one is named before the message and colors the bar
+    pub phases: Vec<Phase>,
+    /// Spinner frames drawn in front of determinate bars, e.g. `presets::DOTS`, so
+    /// the line stays alive while progress stalls; all bars' spinners share one clock
+    pub spinner: Option<Vec<String>>,
+    /// Animation drawn by indeterminate bars
+    pub indeterminate_style: IndeterminateStyle,
+    /// Don't animate on a timer: indeterminate and counting bars only move
+    /// when the caller's own loop calls [`Bar::tick`]
+    pub manual_tick: bool,
+    /// Frames drawn per second at most: the animation steps, the color cycle
+    /// advances and spinners redraw once per frame, and updates arriving in
+    /// between are drawn together with the next one; e.g. `4` over a slow SSH link
+    pub refresh_rate: u32,
+    /// Custom animation for indeterminate bars, replacing `indeterminate_style`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub animator: Option<SharedAnimator>,
+    /// Draw the filled part with `█` and `▏▎▍▌▋▊▉` partial blocks so progress moves in
+    /// eighths of a cell (`filled_char` and `head_char` are ignored); `#` and `-`
+    /// whole cells with an ASCII charset
+    pub smooth: bool,
+    /// Fill determinate bars from the right edge and mirror the built-in
+    /// indeterminate animations, for right-to-left locales; pair with a head
+    /// such as `<`. Smooth bars then advance in half cells, as only `▕` and `▐`
+    /// exist right-aligned
+    pub right_to_left: bool,
+    /// Unicode or ASCII-only glyphs for smooth bars, scan animations, counting
+    /// spinners and truncation; status symbols come from `status_theme`
+    pub charset: Charset,
+    pub left_bracket: String,
+    pub right_bracket: String,
+    /// Symbols and colors used by [`Bar::finish_with_status`]
+    pub status_theme: StatusTheme,
+    /// Advance the bar by one whenever a child from [`Bar::add_child`] finishes
+    pub advance_on_child_finish: bool,
+    /// Stop the bar and its background tasks once this token is cancelled
+    #[cfg(feature = "cancellation")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cancel_token: Option<CancellationToken>,
+    /// What the bar leaves on screen when its cancellation token fires
+    pub on_cancel: CancelAction,
+    /// Bell or desktop notification once the bar finishes, fails or times out
+    pub alert: FinishAlert,
+    /// Hide the cursor while the bar is drawn; it is shown again when the bar ends
+    pub hide_cursor: bool,
+    /// Keep the bar on the bottom rows of the terminal while other output scrolls
+    /// above it, like apt or docker; the final frame is left inline with that output
+    pub pin_to_bottom: bool,
+    /// Take over the terminal's alternate screen and redraw it whole every frame,
+    /// a dashboard for [`MultiBar`]s too tall to scroll with; the screen's previous
+    /// contents come back once the bar finishes, followed by its final frame.
+    /// Output printed meanwhile is drawn over. Takes precedence over `pin_to_bottom`
+    pub alternate_screen: bool,
+    /// Slow-down warning and time limit for indeterminate and counting bars
+    pub deadline: Option<Deadline>,
+    /// Called once if the terminal stops accepting writes and the bar goes silent
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_error: Option<ErrorHook>,
+    /// Draw somewhere other than the terminal; `color_choice`, `hide_cursor` and
+    /// `on_error` only apply to the terminal
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub renderer: Option<RendererFactory>,
+    /// Terminal animation, JSON events or plain appended lines, when no custom
+    /// `renderer` is set
+    pub output: OutputMode,
+    /// Stream the `output` is written to, stderr unless set otherwise
+    pub target: DrawTarget,
+    /// Builds the whole line from the bar's snapshot on every frame, in the
+    /// line color, instead of the built-in layout
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<LineFormatter>,
+    /// Message shown from the progress fraction while the bar has none of its
+    /// own, e.g. `Some(Arc::new(Bar::milestone_message))`; `None` shows nothing
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub message_provider: Option<MessageProvider>,
+    /// Built-in text like `ETA` and `avg`, for localized CLIs
+    pub strings: Strings,
+    /// Name the bar is listed under in a [`Summary`], its message if `None`;
+    /// bars added to a [`MultiBar`] or as children always go by their message
+    pub label: Option<String>,
 }
 
 impl Default for BarConfig {
@@ -61,8 +1158,55 @@ impl Default for BarConfig {
                 Color::Magenta,
                 Color::Cyan,
             ]),
+            color_choice: ColorChoice::Auto,
             color_cycle_delay: 600,
+            color_mode: ColorMode::Cycle,
+            theme: Theme::default(),
             width: 40,
+            fit_to_terminal: true,
+            truncation: Truncation::End,
+            units: Units::Count,
+            count_display: CountDisplay::Hidden,
+            zero_total: ZeroTotal::Complete,
+            show_percent: true,
+            percent_precision: 0,
+            count_label: None,
+            show_rate: false,
+            show_eta: false,
+            rate_smoothing: 0.3,
+            unit_precision: 1,
+            filled_char: '=',
+            empty_char: ' ',
+            head_char: None,
+            phases: Vec::new(),
+            spinner: None,
+            indeterminate_style: IndeterminateStyle::Bounce,
+            manual_tick: false,
+            refresh_rate: 10,
+            animator: None,
+            smooth: false,
+            right_to_left: false,
+            charset: Charset::Auto,
+            left_bracket: "[".to_string(),
+            right_bracket: "]".to_string(),
+            status_theme: StatusTheme::default(),
+            advance_on_child_finish: false,
+            #[cfg(feature = "cancellation")]
+            cancel_token: None,
+            on_cancel: CancelAction::MarkCancelled,
+            alert: FinishAlert::default(),
+            hide_cursor: true,
+            pin_to_bottom: false,
+            alternate_screen: false,
+            deadline: None,
+            on_error: None,
+            renderer: None,
+            output: OutputMode::Auto,
+            target: DrawTarget::Stderr,
+            formatter: None,
+            message_provider: None,
+            strings: Strings::default(),
+            label: None,
         }
     }
 }
@@ -72,63 +1216,533 @@ impl BarConfig {
     pub fn no_colors() -> Self {
         Self {
             colors: None,
-            color_cycle_delay: 600,
-            width: 40,
+            ..Self::default()
+        }
+    }
+
+    /// Time between two frames at `refresh_rate`
+    pub(crate) fn frame_interval(&self) -> Duration {
+        Duration::from_secs(1) / self.refresh_rate.max(1)
+    }
+
+    /// Where the terminal draws live frames
+    fn layout(&self) -> Layout {
+        if self.alternate_screen {
+            Layout::AlternateScreen
+        } else if self.pin_to_bottom {
+            Layout::PinnedToBottom
+        } else {
+            Layout::Inline
         }
     }
 }
 
 #[derive(Clone, Copy)]
 pub enum BarMode {
-    Determinate { current: u64, total: u64 },
-    Indeterminate { position: usize, direction: i8 }, // direction: 1 or -1
+    Determinate,
+    Indeterminate { tick: u64 },
+    Counting { frame_index: usize },
 }
 
 struct BarState {
     mode: BarMode,
-    finished: bool,
     message: String,
     color_index: usize,
+    status: Option<Status>,
+    rate: RateTracker,
+    /// Past the config's soft deadline
+    slow: bool,
+    /// Secondary line drawn under the bar, see [`Bar::set_detail`]
+    detail: Option<String>,
+    /// Time the bar has been running
+    stopwatch: Stopwatch,
 }
 
-pub struct Bar {
-    inner: Arc<Mutex<BarState>>,
-    notify: Arc<Notify>,
-    _draw_task: JoinHandle<()>,
-    _animate_task: Option<JoinHandle<()>>,
-}
+impl BarState {
+    fn new(mode: BarMode, message: String) -> Self {
+        Self {
+            mode,
+            message,
+            color_index: 0,
+            status: None,
+            rate: RateTracker::new(),
+            slow: false,
+            detail: None,
+            stopwatch: Stopwatch::start(Duration::ZERO),
+        }
+    }
 
-impl Bar {
-    /// Creates a new determinate progress bar with a known total
-    pub fn new(total: u64) -> Self {
-        Self::with_config(total, BarConfig::default())
+    /// Feeds `count` to the rate tracker, unless the bar is paused
+    fn sample_rate(&mut self, count: u64, smoothing: f64) {
+        if self.stopwatch.is_running() {
+            self.rate.update(count, smoothing);
+        }
     }
 
-    /// Creates a new determinate progress bar with no colors
-    pub fn new_plain(total: u64) -> Self {
-        Self::with_config(total, BarConfig::no_colors())
+    /// Throughput over the bar's whole run, for `count` done so far
+    fn average_rate(&self, count: u64) -> f64 {
+        match self.stopwatch.elapsed().as_secs_f64() {
+            secs if secs > 0.0 => count as f64 / secs,
+            _ => 0.0,
+        }
     }
+}
 
-    /// Creates a new determinate progress bar with custom configuration
-    pub fn with_config(total: u64, config: BarConfig) -> Self {
-        let state = BarState {
-            mode: BarMode::Determinate { current: 0, total },
-            finished: false,
-            message: String::new(),
-            color_index: 0,
-        };
+/// A bar's running time: the time accumulated over earlier stretches, plus the
+/// current one unless paused, see [`Bar::pause`] and [`Bar::from_snapshot`]
+#[derive(Clone, Copy)]
+struct Stopwatch {
+    /// Time run before `running`
+    carried: Duration,
+    /// Start of the current stretch, `None` while paused
+    running: Option<Instant>,
+}
 
-        let inner = Arc::new(Mutex::new(state));
-        let notify = Arc::new(Notify::new());
+impl Stopwatch {
+    /// Starts running now, with `carried` already run
+    fn start(carried: Duration) -> Self {
+        Self {
+            carried,
+            running: Some(Instant::now()),
+        }
+    }
 
-        let draw_task = Self::spawn_draw_task(inner.clone(), notify.clone(), config);
+    fn pause(&mut self) {
+        if let Some(since) = self.running.take() {
+            self.carried += since.elapsed();
+        }
+    }
 
-        Bar {
-            inner,
-            notify,
-            _draw_task: draw_task,
-            _animate_task: None,
+    fn resume(&mut self) {
+        self.running.get_or_insert_with(Instant::now);
+    }
+
+    fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.carried + self.running.map_or(Duration::ZERO, |since| since.elapsed())
+    }
+}
+
+/// Throughput sampled once per window, smoothed as an exponentially weighted
+/// moving average so bursty progress doesn't make the rate and ETA jump around
+struct RateTracker {
+    sample_at: Instant,
+    sample_count: u64,
+    rate: f64,
+    /// Whether a full window has passed, so `rate` is a real measurement
+    measured: bool,
+}
+
+impl RateTracker {
+    const WINDOW: Duration = Duration::from_secs(1);
+
+    fn new() -> Self {
+        Self {
+            sample_at: Instant::now(),
+            sample_count: 0,
+            rate: 0.0,
+            measured: false,
+        }
+    }
+
+    /// Starts the current window over at `count`, so time spent paused isn't sampled
+    fn restart(&mut self, count: u64) {
+        self.sample_at = Instant::now();
+        self.sample_count = count;
+    }
+
+    /// Samples `count`; `smoothing` is the weight of the new sample, see
+    /// `BarConfig::rate_smoothing`
+    fn update(&mut self, count: u64, smoothing: f64) {
+        let elapsed = self.sample_at.elapsed();
+        if elapsed >= Self::WINDOW {
+            let sample = count.saturating_sub(self.sample_count) as f64 / elapsed.as_secs_f64();
+            // The first sample has nothing to be averaged with
+            self.rate = if self.measured {
+                let smoothing = smoothing.clamp(0.0, 1.0);
+                smoothing * sample + (1.0 - smoothing) * self.rate
+            } else {
+                sample
+            };
+            self.sample_at = Instant::now();
+            self.sample_count = count;
+            self.measured = true;
+        }
+    }
+}
+
+/// The rate shown in a determinate bar's stats
+#[derive(Clone, Copy)]
+enum Throughput {
+    /// Smoothed recent throughput, while the bar runs
+    Live(f64),
+    /// Average over the whole run, on the finished line
+    Average(f64),
+}
+
+/// Progress counters updated on the hot path without taking the state mutex
+struct BarCounters {
+    current: AtomicU64,
+    total: AtomicU64,
+    determinate: AtomicBool,
+    finished: AtomicBool,
+    /// When `finished` was set, for [`summary`]
+    finished_at: OnceLock<Instant>,
+    /// Parent advanced by one when this (child) bar finishes
+    parent: Option<Arc<BarCounters>>,
+    /// Identifies the bar to data-oriented renderers
+    id: u64,
+    /// Items of a weighted bar done so far and in total, see [`Bar::weighted`];
+    /// a total of 0 means the bar doesn't count items
+    items_done: AtomicU64,
+    items_total: AtomicU64,
+    /// Outcomes reported through [`Bar::inc_ok`] and [`Bar::inc_failed`]; both 0
+    /// means the bar isn't split into succeeded and failed parts
+    ok: AtomicU64,
+    failed: AtomicU64,
+    /// Latest drawn state, for [`Bar::subscribe`]
+    snapshots: watch::Sender<ProgressSnapshot>,
+}
+
+impl BarCounters {
+    fn new(total: u64, determinate: bool) -> Self {
+        Self {
+            current: AtomicU64::new(0),
+            total: AtomicU64::new(total),
+            determinate: AtomicBool::new(determinate),
+            finished: AtomicBool::new(false),
+            finished_at: OnceLock::new(),
+            parent: None,
+            id: render::next_id(),
+            items_done: AtomicU64::new(0),
+            items_total: AtomicU64::new(0),
+            ok: AtomicU64::new(0),
+            failed: AtomicU64::new(0),
+            snapshots: watch::Sender::new(ProgressSnapshot {
+                position: 0,
+                total: determinate.then_some(total),
+                message: String::new(),
+                detail: None,
+                finished: false,
+                elapsed: Duration::ZERO,
+            }),
+        }
+    }
+
+    fn inc(&self, delta: u64) {
+        if self.finished.load(Ordering::Acquire) {
+            return;
+        }
+
+        let total = self.total.load(Ordering::Relaxed);
+        let previous = self
+            .current
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                Some(current.saturating_add(delta).min(total))
+            })
+            .unwrap_or_else(|current| current);
+
+        if previous.saturating_add(delta) >= total {
+            self.complete_if_determinate();
+        }
+    }
+
+    /// Advances by one item's `weight` and counts the item as done
+    fn inc_weighted(&self, weight: u64) {
+        if self.finished.load(Ordering::Acquire) {
+            return;
+        }
+        let _ = self
+            .items_done
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |done| {
+                Some(done.saturating_add(1))
+            });
+        self.inc(weight);
+    }
+
+    /// Advances by `delta` items that succeeded or failed
+    fn inc_outcome(&self, delta: u64, failed: bool) {
+        if self.finished.load(Ordering::Acquire) {
+            return;
+        }
+        let outcome = if failed { &self.failed } else { &self.ok };
+        let _ = outcome.fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+            Some(count.saturating_add(delta))
+        });
+        self.inc(delta);
+    }
+
+    /// Succeeded and failed counts, if the bar reports outcomes
+    fn outcomes(&self) -> Option<(u64, u64)> {
+        match (
+            self.ok.load(Ordering::Acquire),
+            self.failed.load(Ordering::Acquire),
+        ) {
+            (0, 0) => None,
+            outcomes => Some(outcomes),
+        }
+    }
+
+    fn set_position(&self, pos: u64) {
+        if self.finished.load(Ordering::Acquire) {
+            return;
+        }
+
+        let total = self.total.load(Ordering::Relaxed);
+        self.current.store(pos.min(total), Ordering::Release);
+
+        if pos >= total {
+            self.complete_if_determinate();
+        }
+    }
+
+    /// Marks the bar finished once the counter reaches the total
+    fn complete_if_determinate(&self) {
+        if self.determinate.load(Ordering::Acquire) {
+            self.mark_finished();
+        }
+    }
+
+    /// Marks the bar finished, advancing the parent the first time only
+    fn mark_finished(&self) {
+        if self.finished.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let _ = self.finished_at.set(Instant::now());
+        if let Some(parent) = &self.parent {
+            parent.inc(1);
+        }
+    }
+}
+
+/// A child bar drawn below its parent, see [`Bar::add_child`]
+#[derive(Clone)]
+struct ChildLine {
+    /// Nesting level, 1 for direct children of the top-level bar
+    depth: usize,
+    inner: Arc<Mutex<BarState>>,
+    counters: Arc<BarCounters>,
+}
+
+/// Columns of indentation per nesting level of child bars
+const CHILD_INDENT: usize = 2;
+
+/// Index just past the bar owning `counters` and everything nested under it
+///
+/// A bar that isn't in the list (the top-level bar of a tree) owns all of it.
+fn subtree_end(lines: &[ChildLine], counters: &Arc<BarCounters>) -> usize {
+    match lines
+        .iter()
+        .position(|line| Arc::ptr_eq(&line.counters, counters))
+    {
+        Some(index) => {
+            let depth = lines[index].depth;
+            lines[index + 1..]
+                .iter()
+                .position(|line| line.depth <= depth)
+                .map_or(lines.len(), |offset| index + 1 + offset)
+        }
+        None => lines.len(),
+    }
+}
+
+/// Cheap, cloneable handle for reporting progress from sync code
+///
+/// Updates go straight to the bar's atomic counters, so a handle can be used
+/// from CPU-bound loops, rayon workers or non-async callbacks without
+/// entering the async runtime.
+#[derive(Clone)]
+pub struct BarHandle {
+    counters: Arc<BarCounters>,
+    notify: Arc<RenderSignal>,
+}
+
+impl BarHandle {
+    /// Increment the progress bar by the specified amount (determinate mode only)
+    pub fn inc(&self, delta: u64) {
+        self.counters.inc(delta);
+        self.notify.notify_one();
+    }
+
+    /// Set the current progress directly (determinate mode only)
+    pub fn set_position(&self, pos: u64) {
+        self.counters.set_position(pos);
+        self.notify.notify_one();
+    }
+
+    /// Marks one item of a weighted bar done, see [`Bar::inc_weighted`]
+    pub fn inc_weighted(&self, weight: u64) {
+        self.counters.inc_weighted(weight);
+        self.notify.notify_one();
+    }
+
+    /// Advances by `delta` items that succeeded, see [`Bar::inc_ok`]
+    pub fn inc_ok(&self, delta: u64) {
+        self.counters.inc_outcome(delta, false);
+        self.notify.notify_one();
+    }
+
+    /// Advances by `delta` items that failed, see [`Bar::inc_failed`]
+    pub fn inc_failed(&self, delta: u64) {
+        self.counters.inc_outcome(delta, true);
+        self.notify.notify_one();
+    }
+}
+
+/// A progress bar; clones share the same bar and drawing tasks
+#[derive(Clone)]
+pub struct Bar {
+    inner: Arc<Mutex<BarState>>,
+    counters: Arc<BarCounters>,
+    notify: Arc<RenderSignal>,
+    config: Arc<BarConfig>,
+    /// Every child bar in the tree, in display order (shared by the whole tree)
+    children: Arc<StdMutex<Vec<ChildLine>>>,
+    /// Nesting level of this bar, 0 for the top-level bar
+    depth: usize,
+    tasks: Arc<BarTasks>,
+}
+
+/// A bar's background tasks, shared by its clones
+struct BarTasks {
+    counters: Arc<BarCounters>,
+    draw_task: StdMutex<Option<JoinHandle<()>>>,
+    /// Closed once the draw task has exited, for every finishing clone to wait on
+    drawn: Option<watch::Receiver<()>>,
+    /// Ticks indeterminate and counting bars; replaced when the mode changes
+    animation: StdMutex<Option<ticker::Ticket>>,
+    /// Steps through `BarConfig::colors` every `color_cycle_delay`
+    _color_cycle: Option<ticker::Ticket>,
+}
+
+impl BarTasks {
+    fn new(
+        counters: &Arc<BarCounters>,
+        draw_task: Option<(JoinHandle<()>, watch::Receiver<()>)>,
+        animation: Option<ticker::Ticket>,
+        color_cycle: Option<ticker::Ticket>,
+    ) -> Arc<Self> {
+        let (draw_task, drawn) = draw_task.unzip();
+        Arc::new(Self {
+            counters: counters.clone(),
+            draw_task: StdMutex::new(draw_task),
+            drawn,
+            animation: StdMutex::new(animation),
+            _color_cycle: color_cycle,
+        })
+    }
+}
+
+impl Drop for BarTasks {
+    // Once the last clone is gone, an unfinished bar stops drawing; aborting the
+    // draw task drops its terminal, which shows the cursor again
+    fn drop(&mut self) {
+        // A finished bar's draw task is left to write its final frame
+        if self.counters.finished.load(Ordering::Acquire) {
+            return;
         }
+
+        let draw_task = self
+            .draw_task
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(draw_task) = draw_task {
+            draw_task.abort();
+        }
+        // Dropping the ticket stops the animation
+    }
+}
+
+/// A bar's state as of its latest frame, see [`Bar::subscribe`] and [`Bar::snapshot`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgressSnapshot {
+    pub position: u64,
+    /// `None` unless the bar is determinate
+    pub total: Option<u64>,
+    pub message: String,
+    /// Text of the detail line, see [`Bar::set_detail`]
+    pub detail: Option<String>,
+    pub finished: bool,
+    /// Time the bar has been running, including time carried over by
+    /// [`Bar::from_snapshot`]; subscribers aren't woken for it alone
+    pub elapsed: Duration,
+}
+
+/// Summary returned by the `finish` methods of [`Bar`] once the final frame is written
+#[derive(Clone, Debug, PartialEq)]
+pub struct FinishReport {
+    /// Time the bar ran until it was finished: paused time is left out, time
+    /// carried over by [`Bar::from_snapshot`] is included
+    pub elapsed: Duration,
+    /// Position the bar finished at (what was counted so far for indeterminate bars)
+    pub total: u64,
+    /// Average progress per second over `elapsed`
+    pub rate: f64,
+    /// Message shown on the final frame
+    pub message: String,
+}
+
+impl Bar {
+    /// Creates a new determinate progress bar with a known total
+    pub fn new(total: u64) -> Self {
+        Self::with_config(total, BarConfig::default())
+    }
+
+    /// Creates a new determinate progress bar with no colors
+    pub fn new_plain(total: u64) -> Self {
+        Self::with_config(total, BarConfig::no_colors())
+    }
+
+    /// Creates a determinate bar for a transfer of `total_bytes`, shown as `12.4 MiB / 1.2 GiB`
+    pub fn new_bytes(total_bytes: u64) -> Self {
+        let config = BarConfig::builder().units(Units::Bytes).build();
+        Self::with_config(total_bytes, config)
+    }
+
+    /// Creates a determinate bar over items of different sizes, e.g. file sizes
+    ///
+    /// The total is the sum of `weights`, so the bar moves with the work
+    /// actually done rather than lurching on every tiny or huge item. Report
+    /// each finished item with [`Bar::inc_weighted`]; a `count_display` shows
+    /// items, e.g. `3/120 files`.
+    pub fn weighted<I>(weights: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self::weighted_with_config(weights, BarConfig::default())
+    }
+
+    /// Like [`Bar::weighted`], with custom configuration
+    pub fn weighted_with_config<I>(weights: I, config: BarConfig) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let (items, total) = weights
+            .into_iter()
+            .fold((0u64, 0u64), |(items, total), weight| {
+                (items + 1, total.saturating_add(weight))
+            });
+        let counters = BarCounters::new(total, true);
+        counters.items_total.store(items, Ordering::Relaxed);
+        Self::spawn(
+            BarState::new(BarMode::Determinate, String::new()),
+            counters,
+            config,
+        )
+    }
+
+    /// Creates a new determinate progress bar with custom configuration
+    pub fn with_config(total: u64, config: BarConfig) -> Self {
+        Self::spawn(
+            BarState::new(BarMode::Determinate, String::new()),
+            BarCounters::new(total, true),
+            config,
+        )
     }
 
     /// Creates an indeterminate progress bar for unknown duration tasks
@@ -142,287 +1756,1513 @@ impl Bar {
     }
 
     /// Creates an indeterminate progress bar with custom configuration
+    ///
+    /// The position still counts up in the background, so it carries over once
+    /// [`Bar::set_total`] makes the bar determinate.
     pub fn indeterminate_with_config(message: impl Into<String>, config: BarConfig) -> Self {
+        let mode = BarMode::Indeterminate { tick: 0 };
+        Self::spawn(
+            BarState::new(mode, message.into()),
+            BarCounters::new(u64::MAX, false),
+            config,
+        )
+    }
+
+    /// Creates an open-ended counter that never finishes on its own
+    ///
+    /// Renders a spinner, the running count and the current rate, e.g.
+    /// `⠧ 48,221 events handled (1.2k/s)`, for daemons and event loops.
+    pub fn counting(label: impl Into<String>) -> Self {
+        Self::counting_with_config(label, BarConfig::default())
+    }
+
+    /// Creates an open-ended counter with custom configuration
+    pub fn counting_with_config(label: impl Into<String>, config: BarConfig) -> Self {
+        let mode = BarMode::Counting { frame_index: 0 };
+        Self::spawn(
+            BarState::new(mode, label.into()),
+            BarCounters::new(u64::MAX, false),
+            config,
+        )
+    }
+
+    /// Creates an indeterminate bar that doesn't animate on a timer, moved by [`Bar::tick`]
+    pub fn indeterminate_manual(message: impl Into<String>, config: BarConfig) -> Self {
+        let config = BarConfigBuilder::from(config).manual_tick(true).build();
+        Self::indeterminate_with_config(message, config)
+    }
+
+    /// Creates an open-ended counter that doesn't animate on a timer, moved by [`Bar::tick`]
+    pub fn counting_manual(label: impl Into<String>, config: BarConfig) -> Self {
+        let config = BarConfigBuilder::from(config).manual_tick(true).build();
+        Self::counting_with_config(label, config)
+    }
+
+    /// Recreates a bar saved with [`Bar::snapshot`], e.g. to resume a job after a restart
+    ///
+    /// The position, total, message, detail and running time carry over; a
+    /// snapshot without a total gives an indeterminate bar. Whether the bar had
+    /// finished doesn't. A position past the total is taken as the total.
+    ///
+    /// `config` is checked like [`Bar::try_with_config`] does.
+    pub fn from_snapshot(
+        snapshot: ProgressSnapshot,
+        config: BarConfig,
+    ) -> Result<Self, ConfigError> {
+        config.validate()?;
+        let (mode, counters, position) = match snapshot.total {
+            Some(total) => (
+                BarMode::Determinate,
+                BarCounters::new(total, true),
+                snapshot.position.min(total),
+            ),
+            None => (
+                BarMode::Indeterminate { tick: 0 },
+                BarCounters::new(u64::MAX, false),
+                snapshot.position,
+            ),
+        };
+        counters.current.store(position, Ordering::Relaxed);
         let state = BarState {
-            mode: BarMode::Indeterminate {
-                position: 0,
-                direction: 1,
-            },
-            finished: false,
-            message: message.into(),
-            color_index: 0,
+            detail: snapshot.detail,
+            stopwatch: Stopwatch::start(snapshot.elapsed),
+            ..BarState::new(mode, snapshot.message)
         };
+        Ok(Self::spawn(state, counters, config))
+    }
+
+    fn spawn(mut state: BarState, counters: BarCounters, config: BarConfig) -> Self {
+        let empty = matches!(state.mode, BarMode::Determinate)
+            && counters.total.load(Ordering::Relaxed) == 0;
+        match config.zero_total {
+            _ if !empty => {}
+            ZeroTotal::Complete => counters.mark_finished(),
+            ZeroTotal::Indeterminate => {
+                state.mode = BarMode::Indeterminate { tick: 0 };
+                counters.determinate.store(false, Ordering::Release);
+                counters.total.store(u64::MAX, Ordering::Release);
+            }
+        }
 
+        let animated = !config.manual_tick
+            && (!matches!(state.mode, BarMode::Determinate) || config.spinner.is_some());
         let inner = Arc::new(Mutex::new(state));
-        let notify = Arc::new(Notify::new());
+        let counters = Arc::new(counters);
+        summary::track_bar(config.label.as_ref(), &counters, &inner);
+        let notify = Arc::new(RenderSignal::new());
+        let config = Arc::new(config);
+        let children = Arc::new(StdMutex::new(Vec::new()));
+        if empty && config.zero_total == ZeroTotal::Complete {
+            // Nothing else would wake the draw task to write the final frame
+            notify.notify_one();
+        }
 
-        let draw_task = Self::spawn_draw_task(inner.clone(), notify.clone(), config.clone());
-        let animate_task = Self::spawn_indeterminate_task(inner.clone(), notify.clone(), config);
+        let animation = animated.then(|| {
+            Self::animate(
+                inner.clone(),
+                counters.clone(),
+                notify.clone(),
+                config.clone(),
+            )
+        });
+        let draw_task = Self::spawn_draw_task(
+            inner.clone(),
+            counters.clone(),
+            notify.clone(),
+            config.clone(),
+            children.clone(),
+        );
+        let color_cycle = Self::cycle_colors(
+            inner.clone(),
+            counters.clone(),
+            notify.clone(),
+            config.clone(),
+        );
 
         Bar {
             inner,
+            tasks: BarTasks::new(&counters, Some(draw_task), animation, color_cycle),
+            counters,
             notify,
-            _draw_task: draw_task,
-            _animate_task: Some(animate_task),
+            config,
+            children,
+            depth: 0,
+        }
+    }
+
+    fn spawn_draw_task(
+        inner: Arc<Mutex<BarState>>,
+        counters: Arc<BarCounters>,
+        notify: Arc<RenderSignal>,
+        config: Arc<BarConfig>,
+        children: Arc<StdMutex<Vec<ChildLine>>>,
+    ) -> (JoinHandle<()>, watch::Receiver<()>) {
+        // Dropped with the task however it ends, closing the channel
+        let (done, drawn) = watch::channel(());
+        let draw_task = task::spawn(async move {
+            let _done = done;
+            let _registration = registry::register("Bar", config.output.resolve_for(config.target));
+            let mut renderer = renderer(
+                config.renderer.as_ref(),
+                config.output,
+                config.target,
+                config.color_choice,
+                config.hide_cursor,
+                config.layout(),
+                config.on_error.clone(),
+                &config.strings,
+            );
+
+            loop {
+                let wake = cancel::wait(&notify, config.cancel_token()).await;
+                let drawing = notify.drawing().await;
+                let mut state = inner.lock().await;
+
+                let started = Instant::now();
+
+                let cancelled = matches!(wake, cancel::Wake::Cancelled);
+                if cancelled {
+                    // Stops the animation and turns further updates into no-ops
+                    counters.mark_finished();
+                    if config.on_cancel == CancelAction::MarkCancelled {
+                        state.status = Some(Status::Cancelled);
+                    }
+                }
+
+                let finished = counters.finished.load(Ordering::Acquire);
+                let mut frame = Frame::default();
+                Self::sample_rate(&mut state, &counters, &config);
+                Self::push_lines(&mut frame, &state, &counters, &config, 0);
+                frame.extend(Self::child_lines(&children, &config).await);
+
+                if cancelled && config.on_cancel == CancelAction::Clear {
+                    renderer.clear();
+                    notify.record_draw(started);
+                    break;
+                }
+
+                if finished {
+                    frame.finish(renderer.as_mut());
+                    notify.record_draw(started);
+                    Self::raise_alert(&state, &config);
+                    break;
+                }
+
+                frame.draw(renderer.as_mut());
+                notify.record_draw(started);
+
+                // Updates until the next frame is due are drawn together
+                drop(state);
+                drop(drawing);
+                cancel::pace(started + config.frame_interval(), config.cancel_token()).await;
+            }
+        });
+        (draw_task, drawn)
+    }
+
+    fn animate(
+        inner: Arc<Mutex<BarState>>,
+        counters: Arc<BarCounters>,
+        notify: Arc<RenderSignal>,
+        config: Arc<BarConfig>,
+    ) -> ticker::Ticket {
+        ticker::register(config.frame_interval(), move || {
+            if counters.finished.load(Ordering::Acquire) {
+                return false;
+            }
+
+            // Rather than hold up every other widget's tick while this bar is
+            // being drawn, its animation skips a beat
+            let mut state = match inner.try_lock() {
+                Ok(state) => state,
+                Err(_) => return true,
+            };
+            if !Self::advance(&mut state, &counters, &config) {
+                return false;
+            }
+            drop(state);
+
+            notify.notify_one();
+            true
+        })
+    }
+
+    /// Calls the user back, as configured by `BarConfig::alert`, once the final frame is up
+    fn raise_alert(state: &BarState, config: &BarConfig) {
+        let status = state.status.unwrap_or(Status::Success);
+        let symbol = &config.status_theme.style(status).symbol;
+        let text = if state.message.is_empty() {
+            symbol.clone()
+        } else {
+            format!("{} {}", symbol, state.message)
+        };
+        alert::raise(
+            config.alert,
+            config.renderer.as_ref(),
+            config.output,
+            config.target,
+            &text,
+        );
+    }
+
+    /// Moves on to the next of `BarConfig::colors` every `color_cycle_delay`
+    ///
+    /// `None` when there is no cycle to run: no colors, a single one, a
+    /// gradient instead, or a zero delay.
+    fn cycle_colors(
+        inner: Arc<Mutex<BarState>>,
+        counters: Arc<BarCounters>,
+        notify: Arc<RenderSignal>,
+        config: Arc<BarConfig>,
+    ) -> Option<ticker::Ticket> {
+        let count = config.colors.as_ref().map_or(0, Vec::len);
+        if count < 2 || config.color_mode != ColorMode::Cycle || config.color_cycle_delay == 0 {
+            return None;
+        }
+
+        let delay = Duration::from_millis(config.color_cycle_delay);
+        Some(ticker::register(delay, move || {
+            if counters.finished.load(Ordering::Acquire) {
+                return false;
+            }
+
+            // A bar being drawn keeps its color a tick longer
+            if let Ok(mut state) = inner.try_lock() {
+                state.color_index = (state.color_index + 1) % count;
+                drop(state);
+                notify.notify_one();
+            }
+            true
+        }))
+    }
+
+    /// Moves the animation one step on, checking the deadline against the bar's running time
+    ///
+    /// Returns `false` once there is nothing left to animate.
+    fn advance(state: &mut BarState, counters: &BarCounters, config: &BarConfig) -> bool {
+        let animated = match state.mode {
+            BarMode::Indeterminate { ref mut tick } => {
+                *tick += 1;
+                true
+            }
+            BarMode::Counting {
+                ref mut frame_index,
+            } => {
+                *frame_index = (*frame_index + 1) % COUNTING_FRAMES.len();
+                state.sample_rate(
+                    counters.current.load(Ordering::Acquire),
+                    config.rate_smoothing,
+                );
+                true
+            }
+            // Only the spinner moves, and it follows the shared clock
+            BarMode::Determinate => false,
+        };
+
+        if let (true, Some(deadline)) = (animated, &config.deadline) {
+            match deadline.check(state.stopwatch.elapsed()) {
+                Lateness::TimedOut => {
+                    // The draw task writes the final frame and exits
+                    state.status = Some(Status::Timeout);
+                    counters.mark_finished();
+                }
+                Lateness::Slow if !state.slow => {
+                    state.slow = true;
+                    if let Some(ref message) = deadline.slow_message {
+                        state.message = message.clone();
+                    }
+                }
+                Lateness::Slow | Lateness::OnTime => {}
+            }
+        }
+        animated || config.spinner.is_some()
+    }
+
+    /// Moves the animation one step on and redraws the bar once
+    ///
+    /// For bars with [`BarConfig::manual_tick`], driven by the caller's own
+    /// loop (a REPL, a game loop) instead of a background timer. The deadline
+    /// counts the bar's running time. Does nothing once the bar is finished.
+    pub async fn tick(&self) {
+        if self.counters.finished.load(Ordering::Acquire) {
+            return;
+        }
+        Self::advance(&mut *self.inner.lock().await, &self.counters, &self.config);
+        self.notify.notify_one();
+    }
+
+    /// Turns the bar into an indeterminate one showing `msg`, without recreating it
+    ///
+    /// The position keeps counting in the background, so [`Bar::set_total`] can
+    /// make the bar determinate again once the size of the work is known.
+    pub async fn set_indeterminate(&self, msg: impl Into<String>) {
+        if self.counters.finished.load(Ordering::Acquire) {
+            return;
+        }
+        {
+            let mut state = self.inner.lock().await;
+            state.mode = BarMode::Indeterminate { tick: 0 };
+            state.message = msg.into();
+            self.counters.determinate.store(false, Ordering::Release);
+            self.counters.total.store(u64::MAX, Ordering::Release);
+        }
+
+        if self.config.manual_tick {
+            self.notify.notify_one();
+            return;
+        }
+
+        // A determinate bar's animation has stopped (or is about to), start afresh;
+        // replacing the previous ticket stops that one
+        let animation = Self::animate(
+            self.inner.clone(),
+            self.counters.clone(),
+            self.notify.clone(),
+            self.config.clone(),
+        );
+        *self
+            .tasks
+            .animation
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(animation);
+        self.notify.notify_one();
+    }
+
+    /// Sets the bar's total, turning an indeterminate or counting bar determinate
+    ///
+    /// The position counted so far is kept, capped at `total`; a bar already at
+    /// its new total finishes. Unless the bar has a `spinner`, its animation
+    /// stops on the next tick.
+    pub async fn set_total(&self, total: u64) {
+        if self.counters.finished.load(Ordering::Acquire) {
+            return;
+        }
+        {
+            let mut state = self.inner.lock().await;
+            state.mode = BarMode::Determinate;
+            self.counters.total.store(total, Ordering::Release);
+            self.counters.determinate.store(true, Ordering::Release);
+            let previous = self.counters.current.fetch_min(total, Ordering::AcqRel);
+            if previous >= total {
+                self.counters.complete_if_determinate();
+            }
+        }
+        self.notify.notify_one();
+    }
+
+    /// Increment the progress bar by the specified amount (determinate mode only)
+    ///
+    /// Only touches atomics, so many tasks can increment the same bar without
+    /// contending on the state lock.
+    pub async fn inc(&self, delta: u64) {
+        self.counters.inc(delta);
+        self.notify.notify_one();
+    }
+
+    /// Advances by one item's `weight` and counts the item as done
+    ///
+    /// For bars from [`Bar::weighted`]; on other bars it's `inc(weight)`, with
+    /// the item count kept but not shown.
+    pub async fn inc_weighted(&self, weight: u64) {
+        self.counters.inc_weighted(weight);
+        self.notify.notify_one();
+    }
+
+    /// Items reported through [`Bar::inc_weighted`] so far
+    pub fn items_done(&self) -> u64 {
+        self.counters.items_done.load(Ordering::Acquire)
+    }
+
+    /// Advances by `delta` items that succeeded
+    ///
+    /// Once a bar reports outcomes, its filled part is split into a part in the
+    /// status theme's success color and one in its error color for the items
+    /// from [`Bar::inc_failed`], and the stats read e.g. `95 ok, 5 failed`.
+    pub async fn inc_ok(&self, delta: u64) {
+        self.counters.inc_outcome(delta, false);
+        self.notify.notify_one();
+    }
+
+    /// Advances by `delta` items that failed, see [`Bar::inc_ok`]
+    pub async fn inc_failed(&self, delta: u64) {
+        self.counters.inc_outcome(delta, true);
+        self.notify.notify_one();
+    }
+
+    /// Items reported through [`Bar::inc_ok`] so far
+    pub fn succeeded(&self) -> u64 {
+        self.counters.ok.load(Ordering::Acquire)
+    }
+
+    /// Items reported through [`Bar::inc_failed`] so far
+    pub fn failed(&self) -> u64 {
+        self.counters.failed.load(Ordering::Acquire)
+    }
+
+    /// Set the current progress directly (determinate mode only)
+    pub async fn set_position(&self, pos: u64) {
+        self.counters.set_position(pos);
+        self.notify.notify_one();
+    }
+
+    /// Identifies the bar among all widgets of the process, e.g. in a [`Summary`]
+    pub fn id(&self) -> u64 {
+        self.counters.id
+    }
+
+    /// Current position (or count, for counting bars)
+    pub fn position(&self) -> u64 {
+        self.counters.current.load(Ordering::Acquire)
+    }
+
+    /// Items per second, smoothed over recent seconds by `BarConfig::rate_smoothing`
+    ///
+    /// Sampled as counting bars animate and as determinate bars redraw.
+    pub async fn rate(&self) -> f64 {
+        self.inner.lock().await.rate.rate
+    }
+
+    /// Adds a determinate child bar drawn indented on the line below this bar's subtree
+    ///
+    /// The child is rendered by the top-level bar's draw task, so it only
+    /// disappears from the screen when that bar finishes. With
+    /// [`BarConfig::advance_on_child_finish`] this bar advances by one as each
+    /// child finishes.
+    pub fn add_child(&self, total: u64, label: impl Into<String>) -> Bar {
+        let parent = self
+            .config
+            .advance_on_child_finish
+            .then(|| self.counters.clone());
+
+        // Place the child after this bar and everything already nested under it
+        Self::attach(
+            &self.children,
+            &self.notify,
+            &self.config,
+            |children| subtree_end(children, &self.counters),
+            self.depth + 1,
+            total,
+            label.into(),
+            parent,
+        )
+    }
+
+    /// Creates a determinate bar drawn as a line of `list` by someone else's draw task
+    #[allow(clippy::too_many_arguments)]
+    fn attach(
+        list: &Arc<StdMutex<Vec<ChildLine>>>,
+        notify: &Arc<RenderSignal>,
+        config: &Arc<BarConfig>,
+        index: impl FnOnce(&[ChildLine]) -> usize,
+        depth: usize,
+        total: u64,
+        label: String,
+        parent: Option<Arc<BarCounters>>,
+    ) -> Bar {
+        let counters = Arc::new(BarCounters {
+            parent,
+            ..BarCounters::new(total, true)
+        });
+        let inner = Arc::new(Mutex::new(BarState::new(BarMode::Determinate, label)));
+        // The config is shared with the other lines, so each goes by its label
+        summary::track_bar(None, &counters, &inner);
+
+        {
+            let mut lines = lock_children(list);
+            let index = index(&lines);
+            lines.insert(
+                index,
+                ChildLine {
+                    depth,
+                    inner: inner.clone(),
+                    counters: counters.clone(),
+                },
+            );
+        }
+        notify.notify_one();
+
+        Bar {
+            inner,
+            tasks: BarTasks::new(&counters, None, None, None),
+            counters,
+            notify: notify.clone(),
+            config: config.clone(),
+            children: list.clone(),
+            depth,
+        }
+    }
+
+    /// Builds the lines of every bar in `children`, indented by nesting level
+    async fn child_lines(children: &StdMutex<Vec<ChildLine>>, config: &BarConfig) -> Frame {
+        let children = lock_children(children).clone();
+        let mut frame = Frame::default();
+        for child in children {
+            let mut state = child.inner.lock().await;
+            Self::sample_rate(&mut state, &child.counters, config);
+            let indent = child.depth * CHILD_INDENT;
+            Self::push_lines(&mut frame, &state, &child.counters, config, indent);
+        }
+        frame
+    }
+
+    /// Feeds a determinate bar's position to its rate tracker on every redraw
+    ///
+    /// Counting bars are sampled on every animation tick instead.
+    fn sample_rate(state: &mut BarState, counters: &BarCounters, config: &BarConfig) {
+        if matches!(state.mode, BarMode::Determinate) {
+            state.sample_rate(
+                counters.current.load(Ordering::Acquire),
+                config.rate_smoothing,
+            );
+        }
+    }
+
+    /// Adds one bar's line, and its detail line while it has one, to `frame`
+    ///
+    /// Also publishes the bar's state to its subscribers.
+    fn push_lines(
+        frame: &mut Frame,
+        state: &BarState,
+        counters: &BarCounters,
+        config: &BarConfig,
+        indent: usize,
+    ) {
+        Self::publish(state, counters, config);
+        let progress = Self::line_progress(state, counters, config);
+        frame.push(
+            Self::bar_line(state, counters, config, indent),
+            progress.clone(),
+        );
+
+        // The detail describes work in flight, so it goes away with the bar's final frame
+        let finished = counters.finished.load(Ordering::Acquire);
+        if let Some(detail) = state.detail.as_ref().filter(|_| !finished) {
+            let indent = " ".repeat(indent + CHILD_INDENT);
+            let columns = config
+                .fit_to_terminal
+                .then(capabilities::terminal_width)
+                .flatten();
+            let detail = fit_text(
+                detail,
+                columns,
+                text_width(&indent),
+                config.truncation,
+                config.charset,
+            );
+            frame.push(
+                vec![
+                    Segment::new(indent, None),
+                    Segment::new(detail, config.theme.message)
+                        .with_style(config.theme.message_style),
+                ],
+                progress,
+            );
+        }
+    }
+
+    /// Sends the bar's current state to its subscribers, if it changed
+    fn publish(state: &BarState, counters: &BarCounters, config: &BarConfig) {
+        let snapshot = Self::frame_snapshot(state, counters, config);
+        counters.snapshots.send_if_modified(|latest| {
+            // Kept current, but time passing alone isn't a change
+            latest.elapsed = snapshot.elapsed;
+            let changed = *latest != snapshot;
+            if changed {
+                *latest = snapshot;
+            }
+            changed
+        });
+    }
+
+    fn frame_snapshot(
+        state: &BarState,
+        counters: &BarCounters,
+        config: &BarConfig,
+    ) -> ProgressSnapshot {
+        let current = counters.current.load(Ordering::Acquire);
+        let total = counters.total.load(Ordering::Relaxed);
+        let determinate = matches!(state.mode, BarMode::Determinate);
+        ProgressSnapshot {
+            position: current,
+            total: determinate.then_some(total),
+            message: if determinate {
+                Self::determinate_message(&state.message, current, total, config).into_owned()
+            } else {
+                state.message.clone()
+            },
+            detail: state.detail.clone(),
+            finished: counters.finished.load(Ordering::Acquire),
+            elapsed: state.stopwatch.elapsed(),
+        }
+    }
+
+    /// What a bar's line shows, for data-oriented renderers
+    fn line_progress(state: &BarState, counters: &BarCounters, config: &BarConfig) -> LineProgress {
+        let current = counters.current.load(Ordering::Acquire);
+        let total = counters.total.load(Ordering::Relaxed);
+        let (position, length, message) = match state.mode {
+            BarMode::Determinate => (
+                Some(current),
+                Some(total),
+                Self::determinate_message(&state.message, current, total, config),
+            ),
+            BarMode::Indeterminate { .. } => (None, None, Cow::Borrowed(state.message.as_str())),
+            BarMode::Counting { .. } => {
+                (Some(current), None, Cow::Borrowed(state.message.as_str()))
+            }
+        };
+        LineProgress {
+            id: counters.id,
+            position,
+            length,
+            message: message.into_owned(),
+            detail: state.detail.clone(),
+        }
+    }
+
+    /// Drains a [`JoinSet`], advancing the bar by one as each task completes
+    ///
+    /// Create the bar with `set.len()` as its total; it finishes on its own once
+    /// every task is done. Results are returned in completion order, including
+    /// tasks that panicked or were cancelled.
+    pub async fn track_join_set<T: 'static>(
+        &self,
+        set: &mut JoinSet<T>,
+    ) -> Vec<Result<T, task::JoinError>> {
+        let mut results = Vec::with_capacity(set.len());
+        while let Some(result) = set.join_next().await {
+            self.inc(1).await;
+            results.push(result);
+        }
+        results
+    }
+
+    /// Returns a sync handle that can report progress without awaiting
+    pub fn handle(&self) -> BarHandle {
+        BarHandle {
+            counters: self.counters.clone(),
+            notify: self.notify.clone(),
+        }
+    }
+
+    /// Rendering performance counters collected by the draw task
+    pub fn render_stats(&self) -> RenderStats {
+        self.notify.stats()
+    }
+
+    /// Watches the bar's position, total, message and whether it finished
+    ///
+    /// A new snapshot is published whenever a frame with changed values is drawn,
+    /// so updates arrive at most at the redraw rate. The channel closes once the
+    /// bar, its handles and its draw task are all gone.
+    pub fn subscribe(&self) -> watch::Receiver<ProgressSnapshot> {
+        self.counters.snapshots.subscribe()
+    }
+
+    /// The bar's current state, to be saved and later restored with [`Bar::from_snapshot`]
+    ///
+    /// Unlike the snapshots sent to subscribers, the message is the bar's own,
+    /// without phase names or messages from `BarConfig::message_provider`.
+    pub async fn snapshot(&self) -> ProgressSnapshot {
+        let state = self.inner.lock().await;
+        ProgressSnapshot {
+            message: state.message.clone(),
+            ..Self::frame_snapshot(&state, &self.counters, &self.config)
+        }
+    }
+
+    /// Stops the bar's clock, e.g. while waiting for the user to answer a prompt
+    ///
+    /// Time spent paused counts neither towards the elapsed time, nor the rate
+    /// and ETA, nor the deadline. The bar is still drawn and can still be
+    /// updated.
+    pub async fn pause(&self) {
+        self.inner.lock().await.stopwatch.pause();
+    }
+
+    /// Starts the clock stopped by [`Bar::pause`] again
+    pub async fn resume(&self) {
+        let mut state = self.inner.lock().await;
+        if !state.stopwatch.is_running() {
+            state.stopwatch.resume();
+            state
+                .rate
+                .restart(self.counters.current.load(Ordering::Acquire));
+        }
+    }
+
+    /// Whether the bar's clock is stopped, see [`Bar::pause`]
+    pub async fn is_paused(&self) -> bool {
+        !self.inner.lock().await.stopwatch.is_running()
+    }
+
+    /// Time the bar has been running
+    async fn elapsed(&self) -> Duration {
+        self.inner.lock().await.stopwatch.elapsed()
+    }
+
+    /// The phase the bar's progress is currently in, see `BarConfig::phases`
+    ///
+    /// Follows the position only; an indeterminate or counting bar has none.
+    pub fn phase(&self) -> Option<&Phase> {
+        if !self.counters.determinate.load(Ordering::Acquire) {
+            return None;
+        }
+        let current = self.counters.current.load(Ordering::Acquire);
+        let total = self.counters.total.load(Ordering::Relaxed);
+        Phase::current(&self.config.phases, Self::progress(current, total))
+    }
+
+    /// Update the message displayed with the progress bar
+    pub async fn set_message(&self, msg: impl Into<String>) {
+        {
+            let mut state = self.inner.lock().await;
+            state.message = msg.into();
+        }
+        self.notify.notify_one();
+    }
+
+    /// Show `detail` on a line of its own under the bar, e.g. the file being processed
+    ///
+    /// Replaces any previous detail; the line is dropped when the bar finishes.
+    pub async fn set_detail(&self, detail: impl Into<String>) {
+        {
+            let mut state = self.inner.lock().await;
+            state.detail = Some(detail.into());
+        }
+        self.notify.notify_one();
+    }
+
+    /// Remove the detail line, going back to a single line
+    pub async fn clear_detail(&self) {
+        {
+            let mut state = self.inner.lock().await;
+            state.detail = None;
+        }
+        self.notify.notify_one();
+    }
+
+    /// Clears the bar, runs `f` while nothing is drawn, then draws the bar again
+    ///
+    /// Hands the terminal over to e.g. a credential prompt or an editor
+    /// mid-progress; other bars sharing the screen are paused too. Progress
+    /// reported from other tasks meanwhile shows up in the redraw. `f` must
+    /// not call [`suspend`] or [`println`].
+    pub async fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        let drawing = self.notify.drawing().await;
+        let result = screen::suspend(f);
+        drop(drawing);
+        self.notify.notify_one();
+        result
+    }
+
+    /// Like [`Bar::suspend`], awaiting the future returned by `f`
+    ///
+    /// Only this bar (and bars drawn with it) is paused; `f` may update the bar
+    /// but not finish it.
+    pub async fn suspend_async<F, Fut, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = R>,
+    {
+        let drawing = self.notify.drawing().await;
+        let result = screen::suspend_async(f()).await;
+        drop(drawing);
+        self.notify.notify_one();
+        result
+    }
+
+    /// Finish the progress bar
+    ///
+    /// Resolves once the final frame has been written.
+    pub async fn finish(&self) -> FinishReport {
+        let elapsed = self.elapsed().await;
+        self.complete();
+        self.notify.notify_one();
+        self.report(elapsed).await
+    }
+
+    /// Finish the progress bar with a custom message
+    pub async fn finish_with_message(&self, msg: impl Into<String>) -> FinishReport {
+        let elapsed = self.elapsed().await;
+        {
+            let mut state = self.inner.lock().await;
+            state.message = msg.into();
+        }
+        self.complete();
+        self.notify.notify_one();
+        self.report(elapsed).await
+    }
+
+    /// Finish the progress bar with an outcome, styled by the config's [`StatusTheme`]
+    ///
+    /// A determinate bar is only filled to 100% on [`Status::Success`]; other
+    /// outcomes keep the position the bar stopped at.
+    pub async fn finish_with_status(&self, status: Status, msg: impl Into<String>) -> FinishReport {
+        let elapsed = self.elapsed().await;
+        {
+            let mut state = self.inner.lock().await;
+            state.message = msg.into();
+            state.status = Some(status);
+        }
+        if status == Status::Success {
+            self.complete();
+        } else {
+            self.counters.mark_finished();
+        }
+        self.notify.notify_one();
+        self.report(elapsed).await
+    }
+
+    /// Awaits `fut`, then finishes the bar according to its result
+    ///
+    /// `Ok` fills the bar like [`Bar::finish`]; `Err` finishes it with
+    /// [`Status::Error`] and the error as the message. The result is passed
+    /// through, so `?` can be used right after.
+    pub async fn wrap_future<F, T, E>(&self, fut: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: fmt::Display,
+    {
+        let result = fut.await;
+        match &result {
+            Ok(_) => {
+                self.finish().await;
+            }
+            Err(err) => {
+                self.finish_with_status(Status::Error, err.to_string())
+                    .await;
+            }
+        }
+        result
+    }
+
+    /// Waits for the draw task to flush the final frame and summarizes the run
+    async fn report(&self, elapsed: Duration) -> FinishReport {
+        // Every clone finishing waits, not just the first
+        if let Some(mut drawn) = self.tasks.drawn.clone() {
+            while drawn.changed().await.is_ok() {}
+        }
+
+        let state = self.inner.lock().await;
+        let current = self.counters.current.load(Ordering::Acquire);
+        let total = self.counters.total.load(Ordering::Relaxed);
+        let message = match state.mode {
+            BarMode::Determinate => {
+                Self::determinate_message(&state.message, current, total, &self.config).into_owned()
+            }
+            BarMode::Indeterminate { .. } | BarMode::Counting { .. } => state.message.clone(),
+        };
+
+        FinishReport {
+            elapsed,
+            total: current,
+            rate: if elapsed.is_zero() {
+                0.0
+            } else {
+                current as f64 / elapsed.as_secs_f64()
+            },
+            message,
         }
     }
 
-    fn spawn_draw_task(
-        inner: Arc<Mutex<BarState>>,
-        notify: Arc<Notify>,
-        config: BarConfig,
-    ) -> JoinHandle<()> {
-        task::spawn(async move {
-            let mut stdout = io::stdout();
+    /// Sets the bar to 100% (meaningful for determinate mode) and marks it finished
+    ///
+    /// A bar that already finished, e.g. because it was cancelled, keeps its position.
+    fn complete(&self) {
+        if self.counters.determinate.load(Ordering::Acquire)
+            && !self.counters.finished.load(Ordering::Acquire)
+        {
+            let total = self.counters.total.load(Ordering::Relaxed);
+            self.counters.current.store(total, Ordering::Release);
+            let items = self.counters.items_total.load(Ordering::Relaxed);
+            self.counters.items_done.fetch_max(items, Ordering::AcqRel);
+        }
+        self.counters.mark_finished();
+    }
 
-            loop {
-                notify.notified().await;
-                let mut state = inner.lock().await;
+    /// Percentage and/or position shown between the bar and the message, e.g. ` 42% 37/120 files `
+    fn determinate_stats(
+        progress: f64,
+        current: u64,
+        total: u64,
+        rate: Option<Throughput>,
+        items: Option<(u64, u64)>,
+        outcomes: Option<(u64, u64)>,
+        config: &BarConfig,
+    ) -> String {
+        let mut parts = Vec::new();
+        if config.show_percent && config.count_display != CountDisplay::InsteadOfPercent {
+            // Rounded half away from zero, as `format!` alone would round half to even
+            let scale = 10f64.powi(config.percent_precision.min(15) as i32);
+            let percent = (progress * 100.0 * scale).round() / scale;
+            parts.push(format!("{:.*}%", config.percent_precision, percent));
+        }
 
-                if state.finished {
-                    Self::draw_bar(&state, &config, &mut stdout);
-                    println!();
-                    break;
+        match config.units {
+            Units::Count if config.count_display == CountDisplay::Hidden => {}
+            Units::Count => {
+                // A weighted bar counts items, not weight units
+                let (current, total) = items.unwrap_or((current, total));
+                let mut count = format!("{}/{}", format::count(current), format::count(total));
+                if let Some(label) = &config.count_label {
+                    count.push(' ');
+                    count.push_str(label);
                 }
+                parts.push(count);
+            }
+            units => parts.push(format!(
+                "{} / {}",
+                format::bytes_with_precision(current as f64, units, config.unit_precision),
+                format::bytes_with_precision(total as f64, units, config.unit_precision)
+            )),
+        }
 
-                Self::draw_bar(&state, &config, &mut stdout);
+        if let Some((ok, failed)) = outcomes {
+            parts.push(format!(
+                "{} {}, {} {}",
+                format::count(ok),
+                config.strings.ok,
+                format::count(failed),
+                config.strings.failed
+            ));
+        }
 
-                // Only cycle colors if colors are enabled
-                if let Some(ref colors) = config.colors {
-                    if !colors.is_empty() {
-                        state.color_index = (state.color_index + 1) % colors.len();
-                    }
+        // `None` until the first measurement
+        let per_sec = |rate| match config.units {
+            Units::Count => format!("{}/s", format::rate(rate)),
+            units => format!(
+                "{}/s",
+                format::bytes_with_precision(rate, units, config.unit_precision)
+            ),
+        };
+        match rate {
+            Some(Throughput::Live(rate)) => {
+                if config.show_rate {
+                    parts.push(per_sec(rate));
+                }
+                if config.show_eta && rate > 0.0 && current < total {
+                    let secs = ((total - current) as f64 / rate).ceil();
+                    parts.push(format!(
+                        "{} {}",
+                        config.strings.eta,
+                        format::duration(Duration::from_secs_f64(secs))
+                    ));
                 }
             }
-        })
-    }
-
-    fn spawn_indeterminate_task(
-        inner: Arc<Mutex<BarState>>,
-        notify: Arc<Notify>,
-        config: BarConfig,
-    ) -> JoinHandle<()> {
-        task::spawn(async move {
-            let bounce_width = config.width / 4; // Size of the moving block
+            Some(Throughput::Average(rate)) if config.show_rate => {
+                parts.push(format!("{} {}", config.strings.average, per_sec(rate)));
+            }
+            Some(Throughput::Average(_)) | None => {}
+        }
 
-            loop {
-                sleep(Duration::from_millis(100)).await;
-
-                let finished = {
-                    let mut state = inner.lock().await;
-                    if state.finished {
-                        true
-                    } else if let BarMode::Indeterminate {
-                        ref mut position,
-                        ref mut direction,
-                    } = state.mode
-                    {
-                        *position = (*position as i32 + *direction as i32) as usize;
-
-                        // Bounce off the edges
-                        if *position >= config.width - bounce_width {
-                            *direction = -1;
-                            *position = config.width - bounce_width;
-                        } else if *position == 0 {
-                            *direction = 1;
-                        }
-                        false
-                    } else {
-                        true // Wrong mode, stop animating
-                    }
-                };
+        if parts.is_empty() {
+            " ".to_string()
+        } else {
+            format!(" {} ", parts.join(" "))
+        }
+    }
 
-                if finished {
-                    break;
+    /// Falls back to the config's `message_provider` until the caller sets its own
+    fn determinate_message<'a>(
+        message: &'a str,
+        current: u64,
+        total: u64,
+        config: &BarConfig,
+    ) -> Cow<'a, str> {
+        let progress = Self::progress(current, total);
+        match Phase::current(&config.phases, progress) {
+            Some(phase) if message.is_empty() => Cow::Owned(phase.name.clone()),
+            Some(phase) => Cow::Owned(format!("{}: {}", phase.name, message)),
+            None => match config.message_provider {
+                Some(ref provider) if message.is_empty() && current > 0 => {
+                    Cow::Owned(provider(progress))
                 }
+                _ => Cow::Borrowed(message),
+            },
+        }
+    }
 
-                notify.notify_one();
-            }
-        })
+    fn progress(current: u64, total: u64) -> f64 {
+        if total == 0 {
+            1.0
+        } else {
+            (current as f64 / total as f64).min(1.0)
+        }
     }
 
-    /// Increment the progress bar by the specified amount (determinate mode only)
-    pub async fn inc(&self, delta: u64) {
-        let mut state = self.inner.lock().await;
-        if !state.finished {
-            if let BarMode::Determinate { current, total } = &mut state.mode {
-                *current = (*current + delta).min(*total);
-
-                // Check if we need to update message and if finished - extract values first
-                let progress = *current as f64 / *total as f64;
-                let current_val = *current;
-                let total_val = *total;
-                let message_empty = state.message.is_empty();
-
-                // Now we can safely update state without conflicting borrows
-                if message_empty {
-                    state.message = match progress {
-                        p if p >= 1.0 => "Complete!".to_string(),
-                        p if p >= 0.75 => "Almost there...".to_string(),
-                        p if p >= 0.5 => "Halfway done".to_string(),
-                        p if p >= 0.25 => "Quarter done".to_string(),
-                        _ => "Working...".to_string(),
-                    };
-                }
+    /// "Working...", "Quarter done", "Halfway done", "Almost there..." and
+    /// "Complete!" by quarters, for use as [`BarConfig::message_provider`]
+    ///
+    /// The default [`Strings`]; see [`Strings::milestone`] for other languages.
+    pub fn milestone_message(progress: f64) -> String {
+        Strings::default().milestone(progress).to_string()
+    }
 
-                if current_val == total_val {
-                    state.finished = true;
+    /// The bar's cells and how many of them, from the left, are filled
+    fn cells(progress: f64, width: usize, config: &BarConfig) -> (String, usize) {
+        let filled_len = (progress * width as f64).round() as usize;
+        let mut bar = vec![config.empty_char; width];
+        bar[..filled_len].fill(config.filled_char);
+
+        // The head replaces the last filled cell while the bar is still moving
+        if let Some(head) = config.head_char {
+            if filled_len > 0 && filled_len < width {
+                bar[filled_len - 1] = head;
+                // A wide head like `🚀` takes the next cell's column too
+                if head.width().unwrap_or(1) > 1 {
+                    bar.remove(filled_len);
                 }
             }
         }
-        drop(state);
-        self.notify.notify_one();
+
+        (bar.iter().collect(), filled_len)
     }
 
-    /// Set the current progress directly (determinate mode only)
-    pub async fn set_position(&self, pos: u64) {
-        let mut state = self.inner.lock().await;
-        if !state.finished {
-            if let BarMode::Determinate { current, total } = &mut state.mode {
-                *current = pos.min(*total);
-
-                // Check if we need to update message and if finished - extract values first
-                let progress = *current as f64 / *total as f64;
-                let current_val = *current;
-                let total_val = *total;
-                let message_empty = state.message.is_empty();
-
-                // Now we can safely update state without conflicting borrows
-                if message_empty {
-                    state.message = match progress {
-                        p if p >= 1.0 => "Complete!".to_string(),
-                        p if p >= 0.75 => "Almost there...".to_string(),
-                        p if p >= 0.5 => "Halfway done".to_string(),
-                        p if p >= 0.25 => "Quarter done".to_string(),
-                        _ => "Working...".to_string(),
-                    };
-                }
+    /// Renders the filled part in eighths of a cell using partial block characters
+    fn smooth_cells(progress: f64, width: usize, config: &BarConfig) -> (String, usize) {
+        const PARTIAL_BLOCKS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
 
-                if current_val == total_val {
-                    state.finished = true;
-                }
-            }
+        if !config.charset.unicode() {
+            let full = ((progress * width as f64).floor() as usize).min(width);
+            let mut bar = vec!['-'; width];
+            bar[..full].fill('#');
+            return (bar.iter().collect(), full);
         }
-        drop(state);
-        self.notify.notify_one();
-    }
 
-    /// Update the message displayed with the progress bar
-    pub async fn set_message(&self, msg: impl Into<String>) {
-        {
-            let mut state = self.inner.lock().await;
-            state.message = msg.into();
+        let eighths = (progress * width as f64 * 8.0).floor() as usize;
+        let full = (eighths / 8).min(width);
+        let remainder = eighths % 8;
+
+        let mut bar = vec![config.empty_char; width];
+        bar[..full].fill('█');
+        if remainder > 0 && full < width {
+            bar[full] = match (config.right_to_left, remainder) {
+                (false, remainder) => PARTIAL_BLOCKS[remainder - 1],
+                (true, 1..=3) => '▕',
+                (true, _) => '▐',
+            };
+            return (bar.iter().collect(), full + 1);
         }
-        self.notify.notify_one();
+
+        (bar.iter().collect(), full)
     }
 
-    /// Finish the progress bar
-    pub async fn finish(&self) {
-        {
-            let mut state = self.inner.lock().await;
-            // Set to 100% if determinate
-            if let BarMode::Determinate {
-                ref mut current,
-                total,
-            } = state.mode
-            {
-                *current = total;
+    /// Splits the bar's cells into runs drawn alike, `part` giving each cell's
+    /// color and style from its index and character
+    fn cell_segments(
+        cells: &str,
+        part: impl Fn(usize, char) -> (Option<Color>, TextStyle),
+    ) -> Vec<Segment> {
+        let mut segments: Vec<Segment> = Vec::new();
+        for (index, cell) in cells.chars().enumerate() {
+            let (color, style) = part(index, cell);
+            match segments.last_mut() {
+                Some(last) if last.color == color && last.style == style => last.text.push(cell),
+                _ => segments.push(Segment::new(cell.to_string(), color).with_style(style)),
             }
-            state.finished = true;
         }
-        self.notify.notify_one();
+        segments
     }
 
-    /// Finish the progress bar with a custom message
-    pub async fn finish_with_message(&self, msg: impl Into<String>) {
-        {
-            let mut state = self.inner.lock().await;
-            // Set to 100% if determinate
-            if let BarMode::Determinate {
-                ref mut current,
-                total,
-            } = state.mode
+    /// Mirrors cells laid out left to right when the bar runs right to left
+    fn directed(cells: String, config: &BarConfig) -> String {
+        if config.right_to_left {
+            cells.chars().rev().collect()
+        } else {
+            cells
+        }
+    }
+
+    /// Builds one bar's line, indented by `indent` columns
+    fn bar_line(
+        state: &BarState,
+        counters: &BarCounters,
+        config: &BarConfig,
+        indent: usize,
+    ) -> Vec<Segment> {
+        let theme = &config.theme;
+        let current = counters.current.load(Ordering::Acquire);
+        let total = counters.total.load(Ordering::Relaxed);
+        let finished = counters.finished.load(Ordering::Acquire);
+
+        let status_style = state.status.map(|status| config.status_theme.style(status));
+        let slow_color = Deadline::slow_color(config.deadline.as_ref(), state.slow);
+        let line_color = match state.status {
+            Some(status) => theme.status_color(status, &config.status_theme),
+            None if finished && theme.finish.is_some() => theme.finish,
+            None if slow_color.is_some() => slow_color,
+            None => Self::phase_color(state, current, total, config)
+                .or(theme.bar)
+                .or_else(|| Self::line_color(state, current, total, config)),
+        };
+
+        // A finished (or slow) line is drawn entirely in its outcome color
+        let part_color = |part: Option<Color>| {
+            if status_style.is_some()
+                || (finished && theme.finish.is_some())
+                || slow_color.is_some()
             {
-                *current = total;
+                line_color
+            } else {
+                part.or(line_color)
             }
-            state.finished = true;
-            state.message = msg.into();
+        };
+
+        let mut segments = Vec::new();
+        if indent > 0 {
+            segments.push(Segment::new(" ".repeat(indent), None));
+        }
+        if let Some(ref formatter) = config.formatter {
+            let line = formatter(&Self::frame_snapshot(state, counters, config));
+            let columns = config
+                .fit_to_terminal
+                .then(capabilities::terminal_width)
+                .flatten();
+            let line = fit_text(&line, columns, indent, config.truncation, config.charset);
+            segments.push(Segment::new(line, line_color));
+            return segments;
+        }
+        if let Some(style) = status_style {
+            segments.push(Segment::new(format!("{} ", style.symbol), line_color));
+        }
+        if let (BarMode::Determinate, Some(frames), None, false) =
+            (state.mode, &config.spinner, status_style, finished)
+        {
+            let frame = spinner_frame(frames, config.charset);
+            segments.push(Segment::new(format!("{} ", frame), line_color));
         }
-        self.notify.notify_one();
-    }
 
-    fn draw_bar(state: &BarState, config: &BarConfig, stdout: &mut io::Stdout) {
-        let display = match state.mode {
-            BarMode::Determinate { current, total } => {
-                let progress = if total == 0 {
-                    1.0
+        // Re-queried on every frame so the layout follows terminal resizes
+        let columns = config
+            .fit_to_terminal
+            .then(capabilities::terminal_width)
+            .flatten();
+
+        // Everything on the line that isn't bar cells or the trailing text
+        let prefix_len = segments.iter().map(|s| text_width(&s.text)).sum::<usize>()
+            + text_width(&config.left_bracket)
+            + text_width(&config.right_bracket);
+
+        match state.mode {
+            BarMode::Determinate => {
+                let progress = Self::progress(current, total);
+                let rate = match finished {
+                    true => Some(Throughput::Average(state.average_rate(current))),
+                    false => state
+                        .rate
+                        .measured
+                        .then_some(Throughput::Live(state.rate.rate)),
+                };
+                let items = match counters.items_total.load(Ordering::Relaxed) {
+                    0 => None,
+                    items => Some((counters.items_done.load(Ordering::Acquire), items)),
+                };
+                let outcomes = counters.outcomes();
+                let stats = Self::determinate_stats(
+                    progress, current, total, rate, items, outcomes, config,
+                );
+                let message = Self::determinate_message(&state.message, current, total, config);
+
+                let width = Self::fit_width(
+                    config,
+                    columns,
+                    prefix_len + text_width(&stats) + text_width(&message),
+                );
+                let used = prefix_len + width + text_width(&stats);
+                let message = fit_text(&message, columns, used, config.truncation, config.charset);
+                let (bar, filled) = if config.smooth {
+                    Self::smooth_cells(progress, width, config)
                 } else {
-                    (current as f64 / total as f64).min(1.0)
+                    Self::cells(progress, width, config)
                 };
-                let filled_len = (progress * config.width as f64).round() as usize;
-                let percent = (progress * 100.0).round();
-
-                format!(
-                    "[{:=<filled$}{:width$}] {:.0}% {}",
-                    "",
-                    "",
-                    percent,
-                    state.message,
-                    filled = filled_len,
-                    width = config.width - filled_len
-                )
+                let bar = Self::directed(bar, config);
+                // One short of `width` after a wide head
+                let cell_count = bar.chars().count();
+                // Failed items take the far end of the fill, at least one cell
+                let failed_cells = match outcomes {
+                    Some((_, 0)) | None => 0,
+                    Some((_, failed)) => ((Self::progress(failed, total) * width as f64).round()
+                        as usize)
+                        .max(1)
+                        .min(filled),
+                };
+                let outcome_color = |status| theme.status_color(status, &config.status_theme);
+                let part = |index: usize, _| {
+                    // Mirrored bars are filled from the right edge
+                    let offset = match config.right_to_left {
+                        false => index,
+                        true => cell_count - 1 - index,
+                    };
+                    match outcomes {
+                        _ if offset >= filled => (line_color, theme.empty_style),
+                        None => (line_color, theme.filled_style),
+                        Some(_) if offset >= filled - failed_cells => {
+                            (outcome_color(Status::Error), theme.filled_style)
+                        }
+                        Some(_) => (outcome_color(Status::Success), theme.filled_style),
+                    }
+                };
+
+                segments.push(Segment::new(
+                    &config.left_bracket,
+                    part_color(theme.brackets),
+                ));
+                segments.extend(Self::cell_segments(&bar, part));
+                segments.push(Segment::new(
+                    &config.right_bracket,
+                    part_color(theme.brackets),
+                ));
+                segments.push(Segment::new(stats, line_color));
+                segments.push(
+                    Segment::new(message, part_color(theme.message))
+                        .with_style(theme.message_style),
+                );
             }
-            BarMode::Indeterminate { position, .. } => {
-                let bounce_width = config.width / 4;
-                let mut bar = vec![' '; config.width];
+            BarMode::Indeterminate { tick } => {
+                let width =
+                    Self::fit_width(config, columns, prefix_len + 1 + text_width(&state.message));
+                let message = fit_text(
+                    &state.message,
+                    columns,
+                    prefix_len + width + 1,
+                    config.truncation,
+                    config.charset,
+                );
+                // Computed from the tick alone, so a resized bar never draws out of bounds
+                let bar = match config.animator {
+                    Some(ref animator) => animation::fit_cells(
+                        &animation::frame(animator, tick, width),
+                        width,
+                        config.empty_char,
+                    ),
+                    None => Self::directed(
+                        config.indeterminate_style.cells(
+                            tick,
+                            width,
+                            config.filled_char,
+                            config.empty_char,
+                            config.charset.unicode(),
+                        ),
+                        config,
+                    ),
+                };
 
-                // Fill the bouncing section
-                for i in position..=(position + bounce_width).min(config.width - 1) {
-                    if i < config.width {
-                        bar[i] = '=';
-                    }
-                }
+                segments.push(Segment::new(
+                    &config.left_bracket,
+                    part_color(theme.brackets),
+                ));
+                // Whatever isn't the empty character counts as filled
+                let part = |_, cell| match cell == config.empty_char {
+                    true => (line_color, theme.empty_style),
+                    false => (line_color, theme.filled_style),
+                };
+                segments.extend(Self::cell_segments(&bar, part));
+                segments.push(Segment::new(
+                    &config.right_bracket,
+                    part_color(theme.brackets),
+                ));
+                segments.push(Segment::new(" ", None));
+                segments.push(
+                    Segment::new(message, part_color(theme.message))
+                        .with_style(theme.message_style),
+                );
+            }
+            BarMode::Counting { frame_index } => {
+                let (count, rate) = match config.units {
+                    Units::Count => (format::count(current), format::rate(state.rate.rate)),
+                    units => (
+                        format::bytes_with_precision(current as f64, units, config.unit_precision),
+                        format::bytes_with_precision(state.rate.rate, units, config.unit_precision),
+                    ),
+                };
+                let spinner = if config.charset.unicode() {
+                    COUNTING_FRAMES[frame_index]
+                } else {
+                    ASCII_COUNTING_FRAMES[frame_index % ASCII_COUNTING_FRAMES.len()]
+                };
+                let count = format!("{} {} ", spinner, count);
+                let rate = format!(" ({}/s)", rate);
+                let used = segments.iter().map(|s| text_width(&s.text)).sum::<usize>()
+                    + text_width(&count)
+                    + text_width(&rate);
+                let message = fit_text(
+                    &state.message,
+                    columns,
+                    used,
+                    config.truncation,
+                    config.charset,
+                );
+
+                segments.push(Segment::new(count, line_color));
+                segments.push(
+                    Segment::new(message, part_color(theme.message))
+                        .with_style(theme.message_style),
+                );
+                segments.push(Segment::new(rate, line_color));
+            }
+        }
+
+        segments
+    }
+
+    /// Bar width that keeps the whole line within `columns`
+    fn fit_width(config: &BarConfig, columns: Option<usize>, other_len: usize) -> usize {
+        let width = config.width.max(1);
+        match columns {
+            // Leave the last column free so the cursor never wraps
+            Some(columns) => width.min(columns.saturating_sub(other_len + 1).max(MIN_FIT_WIDTH)),
+            None => width,
+        }
+    }
 
-                format!("[{}] {}", bar.iter().collect::<String>(), state.message)
+    /// Color of the current phase, for determinate bars with phases
+    fn phase_color(
+        state: &BarState,
+        current: u64,
+        total: u64,
+        config: &BarConfig,
+    ) -> Option<Color> {
+        match state.mode {
+            BarMode::Determinate => {
+                Phase::current(&config.phases, Self::progress(current, total))?.color
             }
-        };
+            BarMode::Indeterminate { .. } | BarMode::Counting { .. } => None,
+        }
+    }
 
-        // Handle colors - if None, just print without colors
-        if let Some(ref colors) = config.colors {
-            let color = colors.get(state.color_index).unwrap_or(&Color::White);
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                SetForegroundColor(*color),
-                Print(&display),
-                ResetColor,
-            );
-        } else {
-            // No colors - just plain text
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                Print(&display),
-            );
+    /// Color from the cycle or gradient, before any theme overrides
+    fn line_color(state: &BarState, current: u64, total: u64, config: &BarConfig) -> Option<Color> {
+        match config.color_mode {
+            ColorMode::Cycle => config
+                .colors
+                .as_ref()
+                .map(|colors| *colors.get(state.color_index).unwrap_or(&Color::White)),
+            ColorMode::Gradient { from, to } => match state.mode {
+                BarMode::Determinate => Some(color::lerp(from, to, Self::progress(current, total))),
+                BarMode::Indeterminate { .. } | BarMode::Counting { .. } => Some(from),
+            },
         }
     }
 }
 
 // --- Throbber (Spinner) Implementation ---
 
+/// Where a throbber draws its spinner, and its status symbol once stopped
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SpinnerPlacement {
+    /// `⠹ Building...`
+    #[default]
+    Before,
+    /// `Building... ⠹`, after the elapsed time if shown
+    After,
+    /// At this column (counted from 0) after the message, moved left to fit
+    /// the terminal; with `fit_to_terminal` the message is cut short before
+    /// it, otherwise a longer message pushes it right
+    Column(usize),
+}
+
+impl SpinnerPlacement {
+    /// Column the glyph starts at when drawn after `text_width` columns of text
+    fn glyph_column(
+        self,
+        text_width: usize,
+        glyph_width: usize,
+        columns: Option<usize>,
+    ) -> Option<usize> {
+        match self {
+            SpinnerPlacement::Before => None,
+            SpinnerPlacement::After => Some(text_width + 1),
+            SpinnerPlacement::Column(column) => {
+                Some(Self::fitted(column, glyph_width, columns).max(text_width + 1))
+            }
+        }
+    }
+
+    /// `column`, moved left so a glyph there stays clear of the last column
+    fn fitted(column: usize, glyph_width: usize, columns: Option<usize>) -> usize {
+        columns.map_or(column, |columns| {
+            column.min(columns.saturating_sub(glyph_width + 1))
+        })
+    }
+}
+
+/// How a [`Throbber`] looks and behaves
+///
+/// Fields are added over time, so configs are made with
+/// [`ThrobberConfig::builder`] or a preset such as [`ThrobberConfig::no_colors`]
+/// rather than struct literals.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
 pub struct ThrobberConfig {
-    pub frames: Vec<&'static str>,
+    pub frames: Vec<String>,
+    /// Custom animation drawn instead of `frames`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub animator: Option<SharedAnimator>,
+    /// Columns the `animator` is asked to fill
+    pub animation_width: usize,
     pub colors: Option<Vec<Color>>, // None = no colors
+    /// Whether colors are emitted at all; `Auto` honors `NO_COLOR` / `CLICOLOR_FORCE`
+    pub color_choice: ColorChoice,
     pub frame_delay: u64,
+    /// Spinner in front of the message, after it or at a fixed column
+    pub spinner_placement: SpinnerPlacement,
+    /// Don't animate on a timer: the spinner only moves when the caller's
+    /// own loop calls [`Throbber::tick`]
+    pub manual_tick: bool,
+    /// Truncate the message so the line fits the terminal
+    pub fit_to_terminal: bool,
+    /// Which end of a message too long for the terminal is cut off
+    pub truncation: Truncation,
+    /// Per-part colors; parts left unset follow the color cycle
+    pub theme: Theme,
+    /// Symbols and colors used by the `stop_*` methods
+    pub status_theme: StatusTheme,
+    /// Stop the throbber and its background tasks once this token is cancelled
+    #[cfg(feature = "cancellation")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cancel_token: Option<CancellationToken>,
+    /// What the throbber leaves on screen when its cancellation token fires
+    pub on_cancel: CancelAction,
+    /// Bell or desktop notification once the throbber stops with a final line
+    pub alert: FinishAlert,
+    /// Hide the cursor while the throbber runs; it is shown again when it stops
+    pub hide_cursor: bool,
+    /// With an ASCII charset, frames with other characters are replaced by `-\|/`
+    /// and truncated messages end in `...`
+    pub charset: Charset,
+    /// Show the time since `start` after the message, e.g. `⠹ Building... 12s`
+    pub show_elapsed: bool,
+    /// Slow-down warning and time limit, counted from `start`
+    pub deadline: Option<Deadline>,
+    /// Called once if the terminal stops accepting writes and the throbber goes silent
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_error: Option<ErrorHook>,
+    /// Draw somewhere other than the terminal; `color_choice`, `hide_cursor` and
+    /// `on_error` only apply to the terminal
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub renderer: Option<RendererFactory>,
+    /// Terminal animation, JSON events or plain appended lines, when no custom
+    /// `renderer` is set
+    pub output: OutputMode,
+    /// Stream the `output` is written to, stderr unless set otherwise
+    pub target: DrawTarget,
+    /// Built-in text like the default message, for localized CLIs
+    pub strings: Strings,
+    /// Name the throbber is listed under in a [`Summary`], its message if `None`
+    pub label: Option<String>,
 }
 
 impl Default for ThrobberConfig {
     fn default() -> Self {
         Self {
-            frames: vec!["|", "/", "-", "\\"],
+            frames: ["|", "/", "-", "\\"].map(String::from).to_vec(),
+            animator: None,
+            animation_width: 1,
             colors: Some(vec![
                 Color::Green,
                 Color::Yellow,
@@ -433,7 +3273,28 @@ impl Default for ThrobberConfig {
                 Color::White,
                 Color::DarkGrey,
             ]),
+            color_choice: ColorChoice::Auto,
             frame_delay: 150,
+            spinner_placement: SpinnerPlacement::Before,
+            manual_tick: false,
+            fit_to_terminal: true,
+            truncation: Truncation::End,
+            theme: Theme::default(),
+            status_theme: StatusTheme::default(),
+            #[cfg(feature = "cancellation")]
+            cancel_token: None,
+            on_cancel: CancelAction::MarkCancelled,
+            alert: FinishAlert::default(),
+            hide_cursor: true,
+            charset: Charset::Auto,
+            show_elapsed: false,
+            deadline: None,
+            on_error: None,
+            renderer: None,
+            output: OutputMode::Auto,
+            target: DrawTarget::Stderr,
+            strings: Strings::default(),
+            label: None,
         }
     }
 }
@@ -442,25 +3303,71 @@ impl ThrobberConfig {
     /// Create a config with no colors (plain text only)
     pub fn no_colors() -> Self {
         Self {
-            frames: vec!["|", "/", "-", "\\"],
             colors: None,
-            frame_delay: 150,
+            ..Self::default()
         }
     }
 }
 
 struct ThrobberState {
+    /// Identifies the throbber to data-oriented renderers
+    id: u64,
+    frames: Vec<String>,
     frame_index: usize,
+    /// Animation ticks since the last `start`, fed to a custom animator
+    tick: u64,
     color_index: usize,
     running: bool,
+    /// When the throbber was last started
+    started: Instant,
+    /// Past the config's soft deadline
+    slow: bool,
+    /// Past the config's hard deadline, the draw task writes the timeout line
+    timed_out: bool,
+    /// Whether a spinner frame is currently on screen
+    visible: bool,
     message: String,
+    registration: Option<registry::Registration>,
+    /// How the throbber last stopped, `None` for [`Throbber::stop`] and `stop_with`
+    outcome: Option<Status>,
+    /// When the throbber last stopped, `None` while it runs
+    stopped: Option<Instant>,
 }
 
+/// A spinner; clones share the same spinner and drawing tasks
+#[derive(Clone)]
 pub struct Throbber {
+    id: u64,
     inner: Arc<Mutex<ThrobberState>>,
-    notify: Arc<Notify>,
+    notify: Arc<RenderSignal>,
+    renderer: Arc<StdMutex<Box<dyn Renderer>>>,
+    config: Arc<ThrobberConfig>,
+    _tasks: Arc<ThrobberTasks>,
+}
+
+/// A throbber's background tasks, shared by its clones
+struct ThrobberTasks {
+    notify: Arc<RenderSignal>,
+    /// Set when the throbber is dropped or cancelled; its tasks exit after cleaning up
+    closed: Arc<AtomicBool>,
     _draw_task: JoinHandle<()>,
-    _animate_task: JoinHandle<()>,
+    /// `None` with `manual_tick`
+    _animation: Option<ticker::Ticket>,
+}
+
+impl Drop for ThrobberTasks {
+    // Once the last clone is gone, the draw task clears a frame still on screen
+    // and shows the cursor on its way out
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+    }
+}
+
+impl Default for Throbber {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Throbber {
@@ -473,94 +3380,246 @@ impl Throbber {
         Self::with_config(ThrobberConfig::no_colors())
     }
 
+    /// Creates a throbber that doesn't animate on a timer, moved by [`Throbber::tick`]
+    pub fn manual(config: ThrobberConfig) -> Self {
+        Self::with_config(
+            ThrobberConfigBuilder::from(config)
+                .manual_tick(true)
+                .build(),
+        )
+    }
+
     pub fn with_config(config: ThrobberConfig) -> Self {
+        let message = config.strings.throbbing.clone();
+        Self::spawn(config, message, false)
+    }
+
+    /// Creates the throbber showing `message`, already running with `start`
+    fn spawn(config: ThrobberConfig, message: String, start: bool) -> Self {
         let state = ThrobberState {
+            id: render::next_id(),
+            frames: config.frames.clone(),
             frame_index: 0,
+            tick: 0,
             color_index: 0,
-            running: false,
-            message: "Throbbing...".to_string(),
+            running: start,
+            started: Instant::now(),
+            slow: false,
+            timed_out: false,
+            visible: false,
+            message,
+            registration: start
+                .then(|| registry::register("Throbber", config.output.resolve_for(config.target))),
+            outcome: None,
+            stopped: None,
         };
+        let id = state.id;
 
         let inner = Arc::new(Mutex::new(state));
-        let notify = Arc::new(Notify::new());
+        summary::track_throbber(config.label.as_ref(), &inner);
+        let notify = Arc::new(RenderSignal::new());
+        let closed = Arc::new(AtomicBool::new(false));
+        let renderer = Arc::new(StdMutex::new(renderer(
+            config.renderer.as_ref(),
+            config.output,
+            config.target,
+            config.color_choice,
+            config.hide_cursor,
+            Layout::Inline,
+            config.on_error.clone(),
+            &config.strings,
+        )));
+
+        let draw_task = Self::spawn_draw_task(
+            inner.clone(),
+            notify.clone(),
+            renderer.clone(),
+            closed.clone(),
+            config.clone(),
+        );
+        let animation = (!config.manual_tick).then(|| {
+            Self::animate(
+                inner.clone(),
+                notify.clone(),
+                closed.clone(),
+                config.clone(),
+            )
+        });
+
+        let tasks = Arc::new(ThrobberTasks {
+            notify: notify.clone(),
+            closed,
+            _draw_task: draw_task,
+            _animation: animation,
+        });
 
-        let draw_task = Self::spawn_draw_task(inner.clone(), notify.clone(), config.clone());
-        let animate_task = Self::spawn_animate_task(inner.clone(), notify.clone(), config);
+        if start {
+            notify.notify_one();
+        }
 
         Throbber {
+            id,
             inner,
             notify,
-            _draw_task: draw_task,
-            _animate_task: animate_task,
+            renderer,
+            config: Arc::new(config),
+            _tasks: tasks,
         }
     }
 
     fn spawn_draw_task(
         inner: Arc<Mutex<ThrobberState>>,
-        notify: Arc<Notify>,
+        notify: Arc<RenderSignal>,
+        renderer: Arc<StdMutex<Box<dyn Renderer>>>,
+        closed: Arc<AtomicBool>,
         config: ThrobberConfig,
     ) -> JoinHandle<()> {
         task::spawn(async move {
-            let mut stdout = io::stdout();
-
             loop {
-                notify.notified().await;
-                let state = inner.lock().await;
+                let wake = cancel::wait(&notify, config.cancel_token()).await;
+                let mut state = inner.lock().await;
+                let mut renderer = lock_renderer(&renderer);
 
-                if !state.running {
-                    let _ = execute!(stdout, MoveToColumn(0), Clear(ClearType::CurrentLine));
+                if closed.load(Ordering::Acquire) {
+                    if state.visible {
+                        renderer.clear();
+                    }
+                    break;
+                }
+
+                if matches!(wake, cancel::Wake::Cancelled) {
+                    // The animation stops once it sees the throbber closed
+                    closed.store(true, Ordering::Release);
+                    state.running = false;
+                    state.registration = None;
+                    if state.visible {
+                        Self::write_cancelled(&state, &config, renderer.as_mut());
+                    }
                     break;
                 }
 
-                Self::draw_frame(&state, &config, &mut stdout);
+                // Stopped (or not started yet): take a frame still on screen down once
+                if !state.running {
+                    if state.visible {
+                        renderer.clear();
+                        state.visible = false;
+                    }
+                    continue;
+                }
+
+                if state.timed_out {
+                    let style = config.status_theme.style(Status::Timeout);
+                    let color = config
+                        .theme
+                        .status_color(Status::Timeout, &config.status_theme);
+                    let message = state.message.clone();
+                    Self::write_final(
+                        &mut state,
+                        &config,
+                        renderer.as_mut(),
+                        Some(Status::Timeout),
+                        &style.symbol,
+                        color,
+                        &message,
+                    );
+                    continue;
+                }
+
+                let started = Instant::now();
+                Frame::single(
+                    Self::frame_line(&state, &config),
+                    Self::line_progress(&state),
+                )
+                .draw(renderer.as_mut());
+                state.visible = true;
+                notify.record_draw(started);
             }
         })
     }
 
-    fn spawn_animate_task(
+    fn animate(
         inner: Arc<Mutex<ThrobberState>>,
-        notify: Arc<Notify>,
+        notify: Arc<RenderSignal>,
+        closed: Arc<AtomicBool>,
         config: ThrobberConfig,
-    ) -> JoinHandle<()> {
-        task::spawn(async move {
-            loop {
-                sleep(Duration::from_millis(config.frame_delay)).await;
-
-                let running = {
-                    let mut state = inner.lock().await;
-                    if !state.running {
-                        false
-                    } else {
-                        state.frame_index = (state.frame_index + 1) % config.frames.len();
-
-                        // Only cycle colors if colors are enabled
-                        if let Some(ref colors) = config.colors {
-                            if !colors.is_empty() {
-                                state.color_index = (state.color_index + 1) % colors.len();
-                            }
-                        }
-                        true
-                    }
-                };
+    ) -> ticker::Ticket {
+        ticker::register(Duration::from_millis(config.frame_delay), move || {
+            if closed.load(Ordering::Acquire) {
+                return false;
+            }
 
-                if !running {
-                    break;
+            // Keeps ticking while stopped so a later `start` animates again; a
+            // throbber being drawn skips the tick rather than hold up the others
+            if let Ok(mut state) = inner.try_lock() {
+                if Self::advance(&mut state, &config) {
+                    drop(state);
+                    notify.notify_one();
                 }
-
-                notify.notify_one();
             }
+            true
         })
     }
 
+    /// Moves a running spinner one frame on; returns whether it is running
+    fn advance(state: &mut ThrobberState, config: &ThrobberConfig) -> bool {
+        if !state.running {
+            return false;
+        }
+        state.frame_index = (state.frame_index + 1) % state.frames.len().max(1);
+        state.tick += 1;
+
+        if let Some(ref deadline) = config.deadline {
+            match deadline.check(state.started.elapsed()) {
+                Lateness::TimedOut => state.timed_out = true,
+                Lateness::Slow if !state.slow => {
+                    state.slow = true;
+                    if let Some(ref message) = deadline.slow_message {
+                        state.message = message.clone();
+                    }
+                }
+                Lateness::Slow | Lateness::OnTime => {}
+            }
+        }
+
+        // Only cycle colors if colors are enabled
+        if let Some(ref colors) = config.colors {
+            if !colors.is_empty() {
+                state.color_index = (state.color_index + 1) % colors.len();
+            }
+        }
+        true
+    }
+
+    /// Moves a started spinner one frame on and redraws it once
+    ///
+    /// For throbbers with [`ThrobberConfig::manual_tick`], driven by the
+    /// caller's own loop (a REPL, a game loop) instead of a background timer.
+    pub async fn tick(&self) {
+        if Self::advance(&mut *self.inner.lock().await, &self.config) {
+            self.notify.notify_one();
+        }
+    }
+
     pub async fn start(&self) {
         {
             let mut state = self.inner.lock().await;
             if !state.running {
                 state.running = true;
+                state.started = Instant::now();
+                state.slow = false;
+                state.timed_out = false;
                 state.frame_index = 0;
+                state.tick = 0;
                 state.color_index = 0;
+                state.registration = Some(registry::register(
+                    "Throbber",
+                    self.config.output.resolve_for(self.config.target),
+                ));
+                state.outcome = None;
+                state.stopped = None;
             }
         }
+        self.notify.notify_one();
     }
 
     pub async fn set_message(&self, msg: impl Into<String>) {
@@ -571,75 +3630,307 @@ impl Throbber {
         self.notify.notify_one();
     }
 
-    pub async fn stop_success(&self, msg: impl Into<String>) {
+    /// The message currently shown next to the spinner
+    pub async fn message(&self) -> String {
+        self.inner.lock().await.message.clone()
+    }
+
+    /// Replace the animation frames, taking effect from the next frame
+    pub async fn set_frames<I, S>(&self, frames: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
         {
-            let mut stdout = io::stdout();
-            let display = format!("{} {}", "✓", msg.into());
-
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                SetForegroundColor(Color::Green),
-                Print(&display),
-                ResetColor,
-            );
+            let mut state = self.inner.lock().await;
+            state.frames = frames.into_iter().map(Into::into).collect();
+            state.frame_index = 0;
         }
+        self.notify.notify_one();
+    }
 
+    /// Rendering performance counters collected by the draw task
+    pub fn render_stats(&self) -> RenderStats {
+        self.notify.stats()
+    }
+
+    /// Identifies the throbber among all widgets of the process, e.g. in a [`Summary`]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Stop the throbber and clear its line
+    pub async fn stop(&self) {
         {
             let mut state = self.inner.lock().await;
+            if state.running {
+                state.stopped = Some(Instant::now());
+            }
             state.running = false;
+            state.registration = None;
         }
+        self.notify.notify_one();
+    }
 
-        println!("")
+    pub async fn stop_success(&self, msg: impl Into<String>) {
+        self.stop_with_status(Status::Success, msg).await;
     }
 
     pub async fn stop_err(&self, msg: impl Into<String>) {
-        {
-            let mut stdout = io::stdout();
-            let display = format!("{} {}", "✗", msg.into());
-
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                SetForegroundColor(Color::Red),
-                Print(&display),
-                ResetColor,
-            );
+        self.stop_with_status(Status::Error, msg).await;
+    }
+
+    /// Starts the throbber, awaits `fut` and stops according to its result
+    ///
+    /// `Ok` stops with success and the current message; `Err` stops with
+    /// the error as the message. The result is passed through, so `?` can be
+    /// used right after.
+    pub async fn run<F, T, E>(&self, fut: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: fmt::Display,
+    {
+        self.start().await;
+        let result = fut.await;
+        match &result {
+            Ok(_) => self.stop_success(self.message().await).await,
+            Err(err) => self.stop_err(err.to_string()).await,
         }
+        result
+    }
 
-        {
-            let mut state = self.inner.lock().await;
-            state.running = false;
+    pub async fn stop_warn(&self, msg: impl Into<String>) {
+        self.stop_with_status(Status::Warning, msg).await;
+    }
+
+    /// Stop the throbber and leave a final line styled by the config's [`StatusTheme`]
+    pub async fn stop_with_status(&self, status: Status, msg: impl Into<String>) {
+        let style = self.config.status_theme.style(status);
+        let color = self
+            .config
+            .theme
+            .status_color(status, &self.config.status_theme);
+        self.stop_final(Some(status), &style.symbol, color, msg.into())
+            .await;
+    }
+
+    /// Stop the throbber and leave a final line with any symbol and color, e.g. `»`
+    ///
+    /// The color is dropped when colors are disabled, like every other color.
+    pub async fn stop_with(&self, symbol: &str, color: Option<Color>, msg: impl Into<String>) {
+        self.stop_final(None, symbol, color, msg.into()).await;
+    }
+
+    async fn stop_final(
+        &self,
+        outcome: Option<Status>,
+        symbol: &str,
+        color: Option<Color>,
+        msg: String,
+    ) {
+        // Hold the state lock while writing so the draw task can't overwrite the final line
+        let mut state = self.inner.lock().await;
+        let mut renderer = lock_renderer(&self.renderer);
+        Self::write_final(
+            &mut state,
+            &self.config,
+            renderer.as_mut(),
+            outcome,
+            symbol,
+            color,
+            &msg,
+        );
+    }
+
+    /// Stops the throbber, replacing its line with `symbol msg`
+    fn write_final(
+        state: &mut ThrobberState,
+        config: &ThrobberConfig,
+        renderer: &mut dyn Renderer,
+        outcome: Option<Status>,
+        symbol: &str,
+        color: Option<Color>,
+        msg: &str,
+    ) {
+        state.running = false;
+        state.timed_out = false;
+        state.registration = None;
+        state.outcome = outcome;
+        state.stopped = Some(Instant::now());
+
+        let progress = LineProgress {
+            message: msg.to_string(),
+            ..Self::line_progress(state)
+        };
+        Frame::single(Self::final_line(symbol, color, msg, config), progress).finish(renderer);
+        state.visible = false;
+
+        alert::raise(
+            config.alert,
+            config.renderer.as_ref(),
+            config.output,
+            config.target,
+            &format!("{} {}", symbol, msg),
+        );
+    }
+
+    /// The line a stopped throbber leaves: its symbol and message in one color
+    fn final_line(
+        symbol: &str,
+        color: Option<Color>,
+        msg: &str,
+        config: &ThrobberConfig,
+    ) -> Vec<Segment> {
+        match config.spinner_placement {
+            SpinnerPlacement::Before => vec![Segment::new(format!("{} {}", symbol, msg), color)],
+            SpinnerPlacement::After | SpinnerPlacement::Column(_) => Self::place(
+                Segment::new(symbol, color),
+                vec![Segment::new(msg, color)],
+                config,
+            ),
         }
+    }
 
-        println!("")
+    /// Leaves the line as configured by `on_cancel`
+    fn write_cancelled(
+        state: &ThrobberState,
+        config: &ThrobberConfig,
+        renderer: &mut dyn Renderer,
+    ) {
+        match config.on_cancel {
+            CancelAction::Clear => renderer.clear(),
+            CancelAction::Abandon => {
+                Frame::single(Self::frame_line(state, config), Self::line_progress(state))
+                    .finish(renderer)
+            }
+            CancelAction::MarkCancelled => {
+                let style = config.status_theme.style(Status::Cancelled);
+                let color = config
+                    .theme
+                    .status_color(Status::Cancelled, &config.status_theme);
+                Frame::single(
+                    Self::final_line(&style.symbol, color, &state.message, config),
+                    Self::line_progress(state),
+                )
+                .finish(renderer);
+            }
+        }
     }
 
-    fn draw_frame(state: &ThrobberState, config: &ThrobberConfig, stdout: &mut io::Stdout) {
-        let frame = config.frames[state.frame_index];
-        let display = format!("{} {}", frame, state.message);
+    /// What the spinner line shows, for data-oriented renderers
+    fn line_progress(state: &ThrobberState) -> LineProgress {
+        LineProgress {
+            id: state.id,
+            position: None,
+            length: None,
+            message: state.message.clone(),
+            detail: None,
+        }
+    }
 
-        // Handle colors - if None, just print without colors
-        if let Some(ref colors) = config.colors {
-            let color = colors.get(state.color_index).unwrap_or(&Color::White);
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                SetForegroundColor(*color),
-                Print(&display),
-                ResetColor,
-            );
+    /// The spinner glyph for the current animation step
+    fn spinner<'a>(
+        frames: &'a [String],
+        frame_index: usize,
+        tick: u64,
+        config: &ThrobberConfig,
+    ) -> Cow<'a, str> {
+        match config.animator {
+            Some(ref animator) => {
+                Cow::Owned(animation::frame(animator, tick, config.animation_width))
+            }
+            None => {
+                let frame = frames.get(frame_index).map_or("", String::as_str);
+                if frame.is_ascii() || config.charset.unicode() {
+                    Cow::Borrowed(frame)
+                } else {
+                    Cow::Borrowed(presets::LINE[frame_index % presets::LINE.len()])
+                }
+            }
+        }
+    }
+
+    /// The color cycle's current color, `None` without colors
+    fn cycle_color(config: &ThrobberConfig, color_index: usize) -> Option<Color> {
+        config
+            .colors
+            .as_ref()
+            .map(|colors| *colors.get(color_index).unwrap_or(&Color::White))
+    }
+
+    /// The spinner line: frame, message and optional elapsed time
+    fn frame_line(state: &ThrobberState, config: &ThrobberConfig) -> Vec<Segment> {
+        let frame = Self::spinner(&state.frames, state.frame_index, state.tick, config);
+        let cycle_color = Self::cycle_color(config, state.color_index);
+        let slow_color = Deadline::slow_color(config.deadline.as_ref(), state.slow);
+        let frame_color = slow_color.or(config.theme.bar).or(cycle_color);
+
+        let columns = config
+            .fit_to_terminal
+            .then(capabilities::terminal_width)
+            .flatten();
+        // Whole seconds only, so the suffix doesn't flicker on every frame
+        let elapsed = if config.show_elapsed {
+            match state.started.elapsed().as_secs() {
+                0 => " 0s".to_string(),
+                secs => format!(" {}", format::duration(Duration::from_secs(secs))),
+            }
         } else {
-            // No colors - just plain text
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                Print(&display),
-            );
+            String::new()
+        };
+        let message = match config.spinner_placement {
+            // Fitted messages end before the glyph's column
+            SpinnerPlacement::Column(column) => fit_text(
+                &state.message,
+                columns.map(|columns| {
+                    SpinnerPlacement::fitted(column, text_width(&frame), Some(columns))
+                }),
+                text_width(&elapsed),
+                config.truncation,
+                config.charset,
+            ),
+            SpinnerPlacement::Before | SpinnerPlacement::After => fit_text(
+                &state.message,
+                columns,
+                text_width(&frame) + 1 + text_width(&elapsed),
+                config.truncation,
+                config.charset,
+            ),
+        };
+
+        Self::place(
+            Segment::new(frame, frame_color).with_style(config.theme.filled_style),
+            vec![
+                Segment::new(message, slow_color.or(config.theme.message).or(frame_color))
+                    .with_style(config.theme.message_style),
+                Segment::new(elapsed, frame_color),
+            ],
+            config,
+        )
+    }
+
+    /// Lays out `glyph` and the `text` after it as `spinner_placement` asks
+    fn place(glyph: Segment, mut text: Vec<Segment>, config: &ThrobberConfig) -> Vec<Segment> {
+        let columns = config
+            .fit_to_terminal
+            .then(capabilities::terminal_width)
+            .flatten();
+        let width = text
+            .iter()
+            .map(|segment| text_width(&segment.text))
+            .sum::<usize>();
+        match config
+            .spinner_placement
+            .glyph_column(width, text_width(&glyph.text), columns)
+        {
+            None => {
+                text.splice(0..0, [glyph, Segment::new(" ", None)]);
+            }
+            Some(column) => {
+                text.push(Segment::new(" ".repeat(column - width), None));
+                text.push(glyph);
+            }
         }
+        text
     }
 }
@@ -0,0 +1,49 @@
+//! Routing `log` records around active widgets (feature `log-bridge`)
+
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::screen;
+
+/// Wraps another logger so its output is printed above the widgets
+///
+/// The inner logger keeps doing all filtering and formatting, e.g. an
+/// `env_logger::Logger` built from `RUST_LOG`; the bridge only takes the
+/// current frame off the screen while a record is written.
+pub struct LogBridge<L> {
+    inner: L,
+}
+
+impl<L: Log + 'static> LogBridge<L> {
+    pub fn new(inner: L) -> Self {
+        Self { inner }
+    }
+
+    /// Installs the bridge as the global logger with the given maximum level
+    ///
+    /// Pass the inner logger's own filter (`env_logger::Logger::filter()`)
+    /// so records it would discard aren't even created.
+    pub fn install(self, max_level: LevelFilter) -> Result<(), SetLoggerError> {
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+impl<L: Log> Log for LogBridge<L> {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.inner.enabled(record.metadata()) {
+            screen::suspend(|| {
+                self.inner.log(record);
+                self.inner.flush();
+            });
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
@@ -0,0 +1,119 @@
+//! Showing progress made in another process, one JSON line per snapshot
+//!
+//! A worker sends its bar's snapshots with [`send_progress`] to whatever
+//! connects it to the parent (its stdout, a Unix socket, ...); the parent
+//! hands the other end to [`mirror_progress`], which replays them on a bar of
+//! its own. The worker's bar would usually be hidden so only the parent draws.
+//!
+//! ```rust,no_run
+//! use throbberous::{mirror, Bar, BarConfig, MultiBar, OutputMode};
+//! use tokio::{io::BufReader, process::Command};
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! // In the worker
+//! let config = BarConfig::builder().output(OutputMode::Hidden).build();
+//! let bar = Bar::with_config(100, config);
+//! let sending = tokio::spawn(mirror::send_progress(bar.subscribe(), tokio::io::stdout()));
+//!
+//! // In the parent
+//! let multi = MultiBar::new();
+//! let mut child = Command::new("worker")
+//!     .stdout(std::process::Stdio::piped())
+//!     .spawn()?;
+//! let stdout = BufReader::new(child.stdout.take().unwrap());
+//! let report = mirror::mirror_progress(stdout, &multi.add(100, "worker")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::Ordering;
+
+use tokio::{
+    io::{self, AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt},
+    sync::watch,
+};
+
+use crate::{Bar, FinishReport, ProgressSnapshot};
+
+/// Writes each snapshot published on `snapshots` to `writer` as a line of JSON
+///
+/// Starts with the current snapshot and returns once a finished one has been
+/// written, or when the channel closes. Snapshots published faster than
+/// `writer` takes them are skipped, only the latest is sent.
+pub async fn send_progress<W>(
+    mut snapshots: watch::Receiver<ProgressSnapshot>,
+    mut writer: W,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    loop {
+        let snapshot = snapshots.borrow_and_update().clone();
+        let mut line = serde_json::to_vec(&snapshot)?;
+        line.push(b'\n');
+        writer.write_all(&line).await?;
+        writer.flush().await?;
+
+        if snapshot.finished || snapshots.changed().await.is_err() {
+            return Ok(());
+        }
+    }
+}
+
+/// Replays the snapshots [`send_progress`] writes to `reader` on `bar`
+///
+/// The bar takes each snapshot's total, position, message and detail; a
+/// snapshot without a total makes it indeterminate. Once a finished snapshot
+/// arrives the bar is finished where the sender's stopped, and its report is
+/// returned. Returns `None` if `reader` ends before that, leaving the bar
+/// unfinished. A line that isn't a snapshot is an [`io::ErrorKind::InvalidData`]
+/// error.
+///
+/// Times come from `bar`'s own clock, not the sender's.
+pub async fn mirror_progress<R>(reader: R, bar: &Bar) -> io::Result<Option<FinishReport>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let snapshot: ProgressSnapshot = serde_json::from_str(&line)?;
+        if let Some(report) = bar.apply_snapshot(snapshot).await {
+            return Ok(Some(report));
+        }
+    }
+    Ok(None)
+}
+
+impl Bar {
+    /// Brings the bar in line with `snapshot`, finishing it once the sender's has
+    async fn apply_snapshot(&self, snapshot: ProgressSnapshot) -> Option<FinishReport> {
+        // The message goes first so a finished snapshot's is on the final frame
+        match snapshot.total {
+            Some(_) => self.set_message(snapshot.message).await,
+            None if self.counters.determinate.load(Ordering::Acquire) => {
+                self.set_indeterminate(snapshot.message).await
+            }
+            None => self.set_message(snapshot.message).await,
+        }
+        match snapshot.detail {
+            Some(detail) => self.set_detail(detail).await,
+            None => self.clear_detail().await,
+        }
+        if let Some(total) = snapshot.total {
+            self.set_total(total).await;
+        }
+        self.set_position(snapshot.position).await;
+
+        if !snapshot.finished {
+            return None;
+        }
+        // Not `finish`, which would fill a bar the sender stopped short of its total
+        let elapsed = self.elapsed().await;
+        self.counters.mark_finished();
+        self.notify.notify_one();
+        Some(self.report(elapsed).await)
+    }
+}
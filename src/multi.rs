@@ -0,0 +1,378 @@
+//! Several independent bars drawn together as one block of lines
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex as StdMutex,
+    },
+    time::Instant,
+};
+
+use tokio::{
+    sync::Mutex,
+    task::{self, JoinHandle},
+};
+
+use crate::{
+    cancel, lock_children, registry, renderer, subtree_end, ticker, Bar, BarConfig, BarCounters,
+    BarMode, BarState, ChildLine, RenderSignal, RenderStats,
+};
+
+/// A block of determinate bars that can grow and shrink while it is drawn
+///
+/// Bars are added, inserted next to each other and removed at runtime, e.g.
+/// one per job as a queue is worked through. Every bar shares this block's
+/// config and is drawn by its single draw task; [`Bar::add_child`] works on
+/// member bars too. [`MultiBar::show_total`] adds a line summing them all up.
+pub struct MultiBar {
+    members: Arc<StdMutex<Vec<ChildLine>>>,
+    /// Line below the block kept at the sum of its bars, see [`MultiBar::show_total`]
+    total: Arc<StdMutex<Option<ChildLine>>>,
+    notify: Arc<RenderSignal>,
+    config: Arc<BarConfig>,
+    closed: Arc<AtomicBool>,
+    remove_finished: Arc<AtomicBool>,
+    draw_task: StdMutex<Option<JoinHandle<()>>>,
+    /// Redraws the block regularly so `BarConfig::spinner` keeps turning
+    _ticker: Option<ticker::Ticket>,
+}
+
+impl Default for MultiBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiBar {
+    /// Creates an empty block with the default bar config
+    pub fn new() -> Self {
+        Self::with_config(BarConfig::default())
+    }
+
+    /// Creates an empty block whose bars have no colors
+    pub fn new_plain() -> Self {
+        Self::with_config(BarConfig::no_colors())
+    }
+
+    /// Creates an empty block whose bars all use `config`
+    pub fn with_config(config: BarConfig) -> Self {
+        let members = Arc::new(StdMutex::new(Vec::new()));
+        let total = Arc::new(StdMutex::new(None));
+        let notify = Arc::new(RenderSignal::new());
+        let config = Arc::new(config);
+        let closed = Arc::new(AtomicBool::new(false));
+        let remove_finished = Arc::new(AtomicBool::new(false));
+
+        let draw_task = Self::spawn_draw_task(
+            members.clone(),
+            total.clone(),
+            notify.clone(),
+            config.clone(),
+            closed.clone(),
+            remove_finished.clone(),
+        );
+        let ticker = config
+            .spinner
+            .is_some()
+            .then(|| Self::tick_spinners(notify.clone(), closed.clone(), &config));
+
+        MultiBar {
+            members,
+            total,
+            notify,
+            config,
+            closed,
+            remove_finished,
+            draw_task: StdMutex::new(Some(draw_task)),
+            _ticker: ticker,
+        }
+    }
+
+    fn tick_spinners(
+        notify: Arc<RenderSignal>,
+        closed: Arc<AtomicBool>,
+        config: &BarConfig,
+    ) -> ticker::Ticket {
+        ticker::register(config.frame_interval(), move || {
+            if closed.load(Ordering::Acquire) {
+                return false;
+            }
+            notify.notify_one();
+            true
+        })
+    }
+
+    fn spawn_draw_task(
+        members: Arc<StdMutex<Vec<ChildLine>>>,
+        total: Arc<StdMutex<Option<ChildLine>>>,
+        notify: Arc<RenderSignal>,
+        config: Arc<BarConfig>,
+        closed: Arc<AtomicBool>,
+        remove_finished: Arc<AtomicBool>,
+    ) -> JoinHandle<()> {
+        task::spawn(async move {
+            let _registration =
+                registry::register("MultiBar", config.output.resolve_for(config.target));
+            let mut renderer = renderer(
+                config.renderer.as_ref(),
+                config.output,
+                config.target,
+                config.color_choice,
+                config.hide_cursor,
+                config.layout(),
+                config.on_error.clone(),
+                &config.strings,
+            );
+
+            // Position and length of finished bars already pruned from the block
+            let mut retired = (0u64, 0u64);
+
+            loop {
+                let wake = cancel::wait(&notify, config.cancel_token()).await;
+                let drawing = notify.drawing().await;
+                let started = Instant::now();
+
+                if remove_finished.load(Ordering::Acquire) {
+                    let (current, length) = prune_finished(&mut lock_children(&members));
+                    retired = (
+                        retired.0.saturating_add(current),
+                        retired.1.saturating_add(length),
+                    );
+                }
+
+                let mut frame = Bar::child_lines(&members, &config).await;
+                let total = lock_total(&total).clone();
+                if let Some(total) = total {
+                    sum_up(&total.counters, &lock_children(&members), retired);
+                    let mut state = total.inner.lock().await;
+                    Bar::sample_rate(&mut state, &total.counters, &config);
+                    Bar::push_lines(&mut frame, &state, &total.counters, &config, 0);
+                }
+                let closing =
+                    closed.load(Ordering::Acquire) || matches!(wake, cancel::Wake::Cancelled);
+
+                if closing {
+                    frame.finish(renderer.as_mut());
+                    notify.record_draw(started);
+                    break;
+                }
+
+                frame.draw(renderer.as_mut());
+                notify.record_draw(started);
+                drop(drawing);
+
+                // Updates until the next frame is due are drawn together
+                cancel::pace(started + config.frame_interval(), config.cancel_token()).await;
+            }
+        })
+    }
+
+    /// Adds a bar at the bottom of the block
+    pub fn add(&self, total: u64, label: impl Into<String>) -> Bar {
+        self.attach(|members| members.len(), total, label.into())
+    }
+
+    /// Adds a bar on the line above `anchor`
+    ///
+    /// Falls back to the bottom of the block if `anchor` isn't one of its bars.
+    pub fn insert_before(&self, anchor: &Bar, total: u64, label: impl Into<String>) -> Bar {
+        self.attach(
+            |members| {
+                members
+                    .iter()
+                    .position(|member| Arc::ptr_eq(&member.counters, &anchor.counters))
+                    .unwrap_or(members.len())
+            },
+            total,
+            label.into(),
+        )
+    }
+
+    /// Adds a bar below `anchor` and any child bars nested under it
+    ///
+    /// Falls back to the bottom of the block if `anchor` isn't one of its bars.
+    pub fn insert_after(&self, anchor: &Bar, total: u64, label: impl Into<String>) -> Bar {
+        self.attach(
+            |members| subtree_end(members, &anchor.counters),
+            total,
+            label.into(),
+        )
+    }
+
+    fn attach(&self, index: impl FnOnce(&[ChildLine]) -> usize, total: u64, label: String) -> Bar {
+        Bar::attach(
+            &self.members,
+            &self.notify,
+            &self.config,
+            index,
+            0,
+            total,
+            label,
+            None,
+        )
+    }
+
+    /// Removes `bar` (and its child bars) from the block, collapsing the display
+    ///
+    /// Returns `false` if the bar wasn't in the block. The bar itself stays
+    /// usable, updates to it are simply no longer drawn.
+    pub fn remove(&self, bar: &Bar) -> bool {
+        let removed = {
+            let mut members = lock_children(&self.members);
+            match members
+                .iter()
+                .position(|member| Arc::ptr_eq(&member.counters, &bar.counters))
+            {
+                Some(index) => {
+                    remove_subtree(&mut members, index);
+                    true
+                }
+                None => false,
+            }
+        };
+        if removed {
+            self.notify.notify_one();
+        }
+        removed
+    }
+
+    /// Adds a line below the block showing the overall progress of its bars
+    ///
+    /// The line's position and length are the sums of those of the top-level
+    /// bars, kept up to date on every redraw; bars dropped by
+    /// [`MultiBar::set_remove_finished`] keep counting towards it. Showing the
+    /// line again starts it over with the new label.
+    pub fn show_total(&self, label: impl Into<String>) {
+        *lock_total(&self.total) = Some(ChildLine {
+            depth: 0,
+            inner: Arc::new(Mutex::new(BarState::new(
+                BarMode::Determinate,
+                label.into(),
+            ))),
+            counters: Arc::new(BarCounters::new(0, true)),
+        });
+        self.notify.notify_one();
+    }
+
+    /// Removes the line added by [`MultiBar::show_total`]
+    pub fn hide_total(&self) {
+        lock_total(&self.total).take();
+        self.notify.notify_one();
+    }
+
+    /// Drop bars from the block as soon as they finish
+    pub fn set_remove_finished(&self, remove: bool) {
+        self.remove_finished.store(remove, Ordering::Release);
+        self.notify.notify_one();
+    }
+
+    /// Number of lines in the block, child bars included
+    pub fn len(&self) -> usize {
+        lock_children(&self.members).len()
+    }
+
+    /// Whether the block has no bars
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Rendering performance counters collected by the draw task
+    pub fn render_stats(&self) -> RenderStats {
+        self.notify.stats()
+    }
+
+    /// Draws the block one last time, leaving it on screen, and stops drawing
+    ///
+    /// Resolves once the final frame has been written.
+    pub async fn finish(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+
+        let draw_task = self
+            .draw_task
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(draw_task) = draw_task {
+            let _ = draw_task.await;
+        }
+    }
+}
+
+impl Drop for MultiBar {
+    // Like an unfinished `Bar`, the block is left as last drawn
+    fn drop(&mut self) {
+        let draw_task = self
+            .draw_task
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(draw_task) = draw_task {
+            draw_task.abort();
+        }
+    }
+}
+
+/// Removes the line at `index` along with the lines nested under it
+fn remove_subtree(members: &mut Vec<ChildLine>, index: usize) {
+    let end = subtree_end(members, &members[index].counters);
+    members.drain(index..end);
+}
+
+/// Removes every finished bar, and whatever is nested under it
+///
+/// Returns the summed position and length of the top-level bars removed.
+fn prune_finished(members: &mut Vec<ChildLine>) -> (u64, u64) {
+    let mut removed = (0u64, 0u64);
+    let mut index = 0;
+    while index < members.len() {
+        if members[index].counters.finished.load(Ordering::Acquire) {
+            if members[index].depth == 0 {
+                let (current, length) = position(&members[index].counters);
+                removed = (
+                    removed.0.saturating_add(current),
+                    removed.1.saturating_add(length),
+                );
+            }
+            remove_subtree(members, index);
+        } else {
+            index += 1;
+        }
+    }
+    removed
+}
+
+/// A bar's position, capped at its length, and its length; nothing for an
+/// indeterminate bar, which has no length to add up
+fn position(counters: &BarCounters) -> (u64, u64) {
+    if !counters.determinate.load(Ordering::Acquire) {
+        return (0, 0);
+    }
+    let length = counters.total.load(Ordering::Relaxed);
+    let current = counters.current.load(Ordering::Acquire);
+    (current.min(length), length)
+}
+
+/// Sets the total line to the sums over the top-level `members` and the
+/// `retired` bars, finished once every member is
+fn sum_up(total: &BarCounters, members: &[ChildLine], retired: (u64, u64)) {
+    let (mut current, mut length) = retired;
+    let mut finished = true;
+    for member in members.iter().filter(|member| member.depth == 0) {
+        let (member_current, member_length) = position(&member.counters);
+        current = current.saturating_add(member_current);
+        length = length.saturating_add(member_length);
+        finished &= member.counters.finished.load(Ordering::Acquire);
+    }
+    total.total.store(length, Ordering::Relaxed);
+    total.current.store(current, Ordering::Release);
+    total
+        .finished
+        .store(finished && length > 0, Ordering::Release);
+}
+
+fn lock_total(total: &StdMutex<Option<ChildLine>>) -> std::sync::MutexGuard<'_, Option<ChildLine>> {
+    total
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
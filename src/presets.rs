@@ -0,0 +1,185 @@
+//! Named spinner frame sets, in the spirit of cli-spinners
+//!
+//! Each preset is available as a frame list and as a [`ThrobberConfig`]
+//! constructor, e.g. `ThrobberConfig::dots()`, and by name as a [`Spinner`].
+//! A few playful bar styles come as [`BarConfig`] constructors, e.g.
+//! `BarConfig::rocket()`.
+
+use std::cmp::Ordering;
+
+use crate::{shared_animator, BarConfig, ThrobberConfig};
+
+pub const DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+pub const LINE: &[&str] = &["-", "\\", "|", "/"];
+
+pub const ARC: &[&str] = &["◜", "◠", "◝", "◞", "◡", "◟"];
+
+pub const MOON: &[&str] = &["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"];
+
+pub const BOUNCING_BALL: &[&str] = &[
+    "( ●    )",
+    "(  ●   )",
+    "(   ●  )",
+    "(    ● )",
+    "(     ●)",
+    "(    ● )",
+    "(   ●  )",
+    "(  ●   )",
+    "( ●    )",
+    "(●     )",
+];
+
+pub const CLOCK: &[&str] = &[
+    "🕛", "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚",
+];
+
+/// A preset by name, for picking a spinner from a config file, e.g. `"dots"`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Spinner {
+    Dots,
+    Line,
+    Arc,
+    Moon,
+    BouncingBall,
+    Clock,
+}
+
+impl Spinner {
+    pub fn frames(self) -> &'static [&'static str] {
+        match self {
+            Spinner::Dots => DOTS,
+            Spinner::Line => LINE,
+            Spinner::Arc => ARC,
+            Spinner::Moon => MOON,
+            Spinner::BouncingBall => BOUNCING_BALL,
+            Spinner::Clock => CLOCK,
+        }
+    }
+
+    /// Milliseconds between frames the preset is designed for
+    pub fn frame_delay(self) -> u64 {
+        match self {
+            Spinner::Dots | Spinner::Moon | Spinner::BouncingBall => 80,
+            Spinner::Line => 130,
+            Spinner::Arc | Spinner::Clock => 100,
+        }
+    }
+}
+
+impl From<Spinner> for ThrobberConfig {
+    fn from(spinner: Spinner) -> Self {
+        Self {
+            frames: spinner
+                .frames()
+                .iter()
+                .map(|frame| frame.to_string())
+                .collect(),
+            frame_delay: spinner.frame_delay(),
+            ..Self::default()
+        }
+    }
+}
+
+impl ThrobberConfig {
+    /// Braille dots: ⠋ ⠙ ⠹ ⠸ ⠼ ⠴ ⠦ ⠧ ⠇ ⠏
+    pub fn dots() -> Self {
+        Spinner::Dots.into()
+    }
+
+    /// Rotating line: - \ | /
+    pub fn line() -> Self {
+        Spinner::Line.into()
+    }
+
+    /// Rotating arc: ◜ ◠ ◝ ◞ ◡ ◟
+    pub fn arc() -> Self {
+        Spinner::Arc.into()
+    }
+
+    /// Moon phases: 🌑 🌒 🌓 🌔 🌕 🌖 🌗 🌘
+    pub fn moon() -> Self {
+        Spinner::Moon.into()
+    }
+
+    /// A ball bouncing between parentheses: ( ●    )
+    pub fn bouncing_ball() -> Self {
+        Spinner::BouncingBall.into()
+    }
+
+    /// Clock faces advancing hour by hour: 🕛 🕐 🕑 …
+    pub fn clock() -> Self {
+        Spinner::Clock.into()
+    }
+}
+
+/// Moon phases from new to full, one column each
+const MOON_CELLS: [char; 5] = ['○', '◔', '◑', '◕', '●'];
+
+impl BarConfig {
+    /// A rocket trailing exhaust over a row of dots: `[~~~~~~🚀·········]`
+    ///
+    /// Indeterminate bars send it flying across and around again.
+    pub fn rocket() -> Self {
+        Self {
+            filled_char: '~',
+            empty_char: '·',
+            head_char: Some('🚀'),
+            animator: Some(shared_animator(|tick: u64, width: usize| {
+                let position = tick as usize % width.saturating_sub(1).max(1);
+                format!(
+                    "{}🚀{}",
+                    " ".repeat(position),
+                    "·".repeat(width.saturating_sub(position + 2))
+                )
+            })),
+            ..Self::default()
+        }
+    }
+
+    /// Pac-Man eating the dots ahead of him: `[      ᗧ•••••••••]`
+    ///
+    /// Indeterminate bars have him chomping his way across and around again.
+    pub fn pacman() -> Self {
+        Self {
+            filled_char: ' ',
+            empty_char: '•',
+            head_char: Some('ᗧ'),
+            animator: Some(shared_animator(|tick: u64, width: usize| {
+                let position = tick as usize % width.max(1);
+                let mouth = if tick.is_multiple_of(2) { 'ᗧ' } else { '●' };
+                (0..width)
+                    .map(|cell| match cell.cmp(&position) {
+                        Ordering::Less => ' ',
+                        Ordering::Equal => mouth,
+                        Ordering::Greater => '•',
+                    })
+                    .collect()
+            })),
+            ..Self::default()
+        }
+    }
+
+    /// Full moons waxing over new ones: `[●●●●●◑○○○○○○○○]`
+    ///
+    /// Indeterminate bars ripple through the phases.
+    pub fn moon() -> Self {
+        Self {
+            filled_char: '●',
+            empty_char: '○',
+            head_char: Some('◑'),
+            animator: Some(shared_animator(|tick: u64, width: usize| {
+                (0..width)
+                    .map(|cell| {
+                        let phase = (tick as usize + cell) % (MOON_CELLS.len() * 2 - 2);
+                        // Waxing, then waning back
+                        MOON_CELLS[phase.min(MOON_CELLS.len() * 2 - 2 - phase)]
+                    })
+                    .collect()
+            })),
+            ..Self::default()
+        }
+    }
+}
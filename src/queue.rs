@@ -0,0 +1,187 @@
+//! Named async tasks run one after another behind a single throbber line
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use crate::{Status, Strings, Throbber, ThrobberConfig};
+
+type Task = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Tasks run in the order pushed, the one running shown with its place in the queue
+///
+/// ```text
+/// ⠹ 3/7: Migrating database
+/// ✗ 6/7 tasks succeeded, 1 failed: Migrating database
+/// ```
+///
+/// Every task runs even if an earlier one failed, unless
+/// [`TaskQueue::fail_fast`] is set.
+pub struct TaskQueue {
+    tasks: Vec<(String, Task)>,
+    config: ThrobberConfig,
+    fail_fast: bool,
+}
+
+/// How one task of a [`TaskQueue`] went
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskOutcome {
+    pub name: String,
+    /// [`Status::Cancelled`] for tasks skipped after a failure with `fail_fast`
+    pub status: Status,
+    pub elapsed: Duration,
+    /// The error a failed task returned
+    pub error: Option<String>,
+}
+
+/// Returned by [`TaskQueue::run`], one outcome per task in order
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueueReport {
+    pub tasks: Vec<TaskOutcome>,
+}
+
+impl QueueReport {
+    /// Number of tasks that ended with `status`
+    pub fn count(&self, status: Status) -> usize {
+        self.tasks
+            .iter()
+            .filter(|task| task.status == status)
+            .count()
+    }
+
+    /// Whether every task succeeded
+    pub fn is_success(&self) -> bool {
+        self.tasks.iter().all(|task| task.status == Status::Success)
+    }
+
+    /// The summary line left on screen, e.g. `6/7 tasks succeeded, 1 failed: Migrating database`
+    fn summary(&self, strings: &Strings) -> String {
+        let mut summary = format!(
+            "{}/{} {}",
+            self.count(Status::Success),
+            self.tasks.len(),
+            strings.tasks_succeeded
+        );
+        let failed: Vec<&str> = self
+            .tasks
+            .iter()
+            .filter(|task| task.status == Status::Error)
+            .map(|task| task.name.as_str())
+            .collect();
+        if !failed.is_empty() {
+            summary += &format!(
+                ", {} {}: {}",
+                failed.len(),
+                strings.failed,
+                failed.join(", ")
+            );
+        }
+        match self.count(Status::Cancelled) {
+            0 => {}
+            skipped => summary += &format!(", {} {}", skipped, strings.skipped),
+        }
+        summary
+    }
+}
+
+impl Default for TaskQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskQueue {
+    pub fn new() -> Self {
+        Self::with_config(ThrobberConfig::default())
+    }
+
+    /// A queue whose throbber is drawn with `config`
+    pub fn with_config(config: ThrobberConfig) -> Self {
+        Self {
+            tasks: Vec::new(),
+            config,
+            fail_fast: false,
+        }
+    }
+
+    /// Adds `task` to the end of the queue, shown as `name` while it runs
+    ///
+    /// The task doesn't start until [`TaskQueue::run`] gets to it.
+    pub fn push<F, T, E>(&mut self, name: impl Into<String>, task: F) -> &mut Self
+    where
+        F: Future<Output = Result<T, E>> + Send + 'static,
+        E: fmt::Display,
+    {
+        let task = async move { task.await.map(|_| ()).map_err(|err| err.to_string()) };
+        self.tasks.push((name.into(), Box::pin(task)));
+        self
+    }
+
+    /// Skip the remaining tasks once one fails
+    pub fn fail_fast(&mut self, fail_fast: bool) -> &mut Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Runs the tasks one after another and leaves a summary line on screen
+    pub async fn run(self) -> QueueReport {
+        let mut report = QueueReport::default();
+        if self.tasks.is_empty() {
+            return report;
+        }
+
+        let total = self.tasks.len();
+        let strings = self.config.strings.clone();
+        let throbber = Throbber::builder()
+            .config(self.config)
+            .message(progress(1, total, &self.tasks[0].0))
+            .auto_start(true)
+            .spawn();
+
+        let mut failed = false;
+        for (index, (name, task)) in self.tasks.into_iter().enumerate() {
+            if failed && self.fail_fast {
+                report.tasks.push(TaskOutcome {
+                    name,
+                    status: Status::Cancelled,
+                    elapsed: Duration::ZERO,
+                    error: None,
+                });
+                continue;
+            }
+
+            throbber
+                .set_message(progress(index + 1, total, &name))
+                .await;
+            let started = Instant::now();
+            let result = task.await;
+            failed |= result.is_err();
+            report.tasks.push(TaskOutcome {
+                name,
+                status: match result {
+                    Ok(()) => Status::Success,
+                    Err(_) => Status::Error,
+                },
+                elapsed: started.elapsed(),
+                error: result.err(),
+            });
+        }
+
+        let status = match report.is_success() {
+            true => Status::Success,
+            false => Status::Error,
+        };
+        throbber
+            .stop_with_status(status, report.summary(&strings))
+            .await;
+        report
+    }
+}
+
+/// `3/7: Migrating database`
+fn progress(number: usize, total: usize, name: &str) -> String {
+    format!("{}/{}: {}", number, total, name)
+}
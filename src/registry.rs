@@ -0,0 +1,79 @@
+//! Process-wide registry of widgets that are currently drawing
+//!
+//! Each `Bar`/`Throbber` redraws "its" line with a carriage return, so two
+//! independently created widgets that are active at the same time fight over
+//! the same line. The registry notices that and reports it through a hook.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, RwLock,
+};
+
+use crate::OutputMode;
+
+/// Details passed to the conflict hook
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WidgetConflict {
+    /// Kind of the widget that just became active (`"Bar"` or `"Throbber"`)
+    pub widget: &'static str,
+    /// Number of widgets active, including the new one
+    pub active: usize,
+}
+
+type ConflictHook = Arc<dyn Fn(&WidgetConflict) + Send + Sync>;
+
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+static HOOK: RwLock<Option<ConflictHook>> = RwLock::new(None);
+
+/// Install a hook called whenever a widget becomes active while another one
+/// is already drawing to the terminal
+///
+/// Nothing is reported without a hook. Widgets whose output is hidden or
+/// JSON lines never conflict, and aren't counted.
+pub fn set_conflict_hook(hook: impl Fn(&WidgetConflict) + Send + Sync + 'static) {
+    *HOOK
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Number of widgets currently drawing, not counting hidden or JSON ones
+pub fn active_widgets() -> usize {
+    ACTIVE.load(Ordering::Acquire)
+}
+
+/// Keeps a widget counted as active until dropped
+pub(crate) struct Registration {
+    counted: bool,
+}
+
+/// Counts a widget drawing with `output`, already resolved, as active
+pub(crate) fn register(widget: &'static str, output: OutputMode) -> Registration {
+    // Neither takes over a line of the terminal
+    if matches!(output, OutputMode::Hidden | OutputMode::Json) {
+        return Registration { counted: false };
+    }
+    let active = ACTIVE.fetch_add(1, Ordering::AcqRel) + 1;
+    if active > 1 {
+        report(WidgetConflict { widget, active });
+    }
+    Registration { counted: true }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        if self.counted {
+            ACTIVE.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+fn report(conflict: WidgetConflict) {
+    let hook = HOOK
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+
+    if let Some(hook) = hook {
+        hook(&conflict);
+    }
+}
@@ -0,0 +1,587 @@
+//! Pluggable output backends for widgets
+//!
+//! Widgets lay out each frame as lines of colored [`Segment`]s and hand them
+//! to a [`Renderer`]. The default renderer draws them on the terminal with
+//! crossterm; others can append plain lines to a log, emit JSON or record
+//! frames in memory for tests.
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Write as _,
+    io::{self, IsTerminal, Write},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+};
+
+use crossterm::style::Color;
+
+use crate::{Strings, TextStyle};
+
+/// A run of text drawn in one color and style
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Segment {
+    pub text: String,
+    /// `None` draws in the terminal's default color
+    pub color: Option<Color>,
+    /// Background and attributes, plain by default
+    pub style: TextStyle,
+}
+
+impl Segment {
+    pub fn new(text: impl Into<String>, color: Option<Color>) -> Self {
+        Self {
+            text: text.into(),
+            color,
+            style: TextStyle::default(),
+        }
+    }
+
+    pub fn with_style(mut self, style: TextStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Where a widget's frames go
+///
+/// A widget draws a block of one or more lines, replacing its previous frame
+/// each time, until it either leaves a final frame behind or takes its frame
+/// away again. A throbber that is started again afterwards begins a new block.
+pub trait Renderer: Send {
+    /// Replaces the widget's current frame with `lines`
+    fn draw(&mut self, lines: &[Vec<Segment>]);
+
+    /// Leaves `lines` behind as the widget's final frame and ends the block
+    fn finish(&mut self, lines: &[Vec<Segment>]);
+
+    /// Takes the widget's current frame away and ends the block
+    fn clear(&mut self);
+
+    /// Called before every `draw` and `finish` with what each of their lines shows
+    ///
+    /// For renderers that report data rather than text; does nothing by default.
+    fn progress(&mut self, _lines: &[LineProgress]) {}
+}
+
+/// The data behind one drawn line
+///
+/// A widget drawn on several lines, like a bar with a detail line, repeats
+/// its data for each of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineProgress {
+    /// Identifies the bar or throbber the line belongs to, unique within the process
+    pub id: u64,
+    /// `None` for throbbers and indeterminate bars
+    pub position: Option<u64>,
+    /// `None` unless the bar is determinate
+    pub length: Option<u64>,
+    pub message: String,
+    /// Text of the bar's detail line, if it has one
+    pub detail: Option<String>,
+}
+
+/// Hands out ids for [`LineProgress::id`]
+pub(crate) fn next_id() -> u64 {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A widget's lines along with the data they show
+#[derive(Default)]
+pub(crate) struct Frame {
+    pub(crate) lines: Vec<Vec<Segment>>,
+    pub(crate) progress: Vec<LineProgress>,
+}
+
+impl Frame {
+    pub(crate) fn single(line: Vec<Segment>, progress: LineProgress) -> Self {
+        let mut frame = Self::default();
+        frame.push(line, progress);
+        frame
+    }
+
+    pub(crate) fn push(&mut self, line: Vec<Segment>, progress: LineProgress) {
+        self.lines.push(line);
+        self.progress.push(progress);
+    }
+
+    pub(crate) fn extend(&mut self, other: Frame) {
+        self.lines.extend(other.lines);
+        self.progress.extend(other.progress);
+    }
+
+    pub(crate) fn draw(&self, renderer: &mut dyn Renderer) {
+        renderer.progress(&self.progress);
+        renderer.draw(&self.lines);
+    }
+
+    pub(crate) fn finish(&self, renderer: &mut dyn Renderer) {
+        renderer.progress(&self.progress);
+        renderer.finish(&self.lines);
+    }
+}
+
+/// Builds the renderer for each widget, see `BarConfig::renderer`
+pub type RendererFactory = Arc<dyn Fn() -> Box<dyn Renderer> + Send + Sync>;
+
+/// The stream widgets write to
+///
+/// Progress output goes to stderr by convention, so `mytool | jq` only pipes
+/// the tool's actual output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DrawTarget {
+    Stdout,
+    #[default]
+    Stderr,
+}
+
+impl DrawTarget {
+    /// Whether the stream is attached to a terminal
+    pub fn is_terminal(self) -> bool {
+        match self {
+            DrawTarget::Stdout => io::stdout().is_terminal(),
+            DrawTarget::Stderr => io::stderr().is_terminal(),
+        }
+    }
+
+    /// Writes and flushes `buf` in one go
+    pub(crate) fn write_all(self, buf: &[u8]) -> io::Result<()> {
+        match self {
+            DrawTarget::Stdout => {
+                let mut out = io::stdout().lock();
+                out.write_all(buf).and_then(|_| out.flush())
+            }
+            DrawTarget::Stderr => {
+                let mut out = io::stderr().lock();
+                out.write_all(buf).and_then(|_| out.flush())
+            }
+        }
+    }
+}
+
+/// How widgets draw when no custom `renderer` is configured
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum OutputMode {
+    /// Follow the `THROBBEROUS_OUTPUT` environment variable (`terminal`, `json`,
+    /// `append`, `accessible` or `hidden`); without it, append in CI (`CI=true`), on a
+    /// `TERM=dumb` terminal or when the [`DrawTarget`] isn't a terminal, and
+    /// animate otherwise
+    #[default]
+    Auto,
+    /// Animated frames redrawn in place
+    Terminal,
+    /// Newline-delimited JSON events, one whenever a line's data changes,
+    /// e.g. `{"id":1,"pos":50,"len":100,"msg":"Downloading","done":false}`
+    Json,
+    /// Plain lines without animation, printed when a widget starts, crosses
+    /// another 10% or changes its message, and when it finishes; for CI logs
+    Append,
+    /// Plain sentences for screen readers instead of redrawn lines, e.g.
+    /// `Downloading: 25 percent complete`, announced at every quarter, when a
+    /// message changes and when a widget finishes
+    Accessible,
+    /// Nothing at all; widgets keep counting and report as usual
+    Hidden,
+}
+
+impl OutputMode {
+    /// `Auto` resolved to the mode actually used on the default [`DrawTarget`]
+    pub fn resolve(self) -> OutputMode {
+        self.resolve_for(DrawTarget::default())
+    }
+
+    /// `Auto` resolved to the mode actually used on `target`
+    ///
+    /// `THROBBEROUS_DISABLED=1` turns every mode into `Hidden`, so output can
+    /// be silenced, e.g. in cron jobs, without touching the program.
+    pub fn resolve_for(self, target: DrawTarget) -> OutputMode {
+        match self {
+            _ if disabled() => OutputMode::Hidden,
+            OutputMode::Auto => auto_output_mode(target),
+            mode => mode,
+        }
+    }
+}
+
+/// Whether `THROBBEROUS_DISABLED` is set, decided once
+fn disabled() -> bool {
+    static DISABLED: OnceLock<bool> = OnceLock::new();
+    *DISABLED.get_or_init(|| {
+        env::var("THROBBEROUS_DISABLED")
+            .is_ok_and(|value| !matches!(value.trim(), "" | "0" | "false"))
+    })
+}
+
+/// The mode `Auto` stands for on `target` in this process, decided once per stream
+fn auto_output_mode(target: DrawTarget) -> OutputMode {
+    static MODES: [OnceLock<OutputMode>; 2] = [OnceLock::new(), OnceLock::new()];
+    *MODES[target as usize].get_or_init(|| {
+        let requested = env::var("THROBBEROUS_OUTPUT").unwrap_or_default();
+        match requested.trim().to_ascii_lowercase().as_str() {
+            "terminal" => OutputMode::Terminal,
+            "json" => OutputMode::Json,
+            "append" => OutputMode::Append,
+            "accessible" => OutputMode::Accessible,
+            "hidden" => OutputMode::Hidden,
+            _ if in_ci() || dumb_terminal() || !target.is_terminal() => OutputMode::Append,
+            _ => OutputMode::Terminal,
+        }
+    })
+}
+
+/// Whether a CI service set `CI`, as GitHub Actions, GitLab and most others do
+fn in_ci() -> bool {
+    env::var("CI").is_ok_and(|value| !matches!(value.trim(), "" | "0" | "false"))
+}
+
+/// Whether `TERM=dumb` says the terminal can't move the cursor
+fn dumb_terminal() -> bool {
+    env::var("TERM").is_ok_and(|term| term == "dumb")
+}
+
+// --- Hidden Output ---
+
+/// Draws nothing, for [`OutputMode::Hidden`]
+pub(crate) struct HiddenRenderer;
+
+impl Renderer for HiddenRenderer {
+    fn draw(&mut self, _lines: &[Vec<Segment>]) {}
+
+    fn finish(&mut self, _lines: &[Vec<Segment>]) {}
+
+    fn clear(&mut self) {}
+}
+
+// --- Append-Only Output ---
+
+/// Percent steps at which append mode prints a determinate bar again
+const APPEND_STEP_PERCENT: u64 = 10;
+
+/// Prints a plain line whenever a line's data changes meaningfully
+#[derive(Default)]
+pub(crate) struct AppendRenderer {
+    target: DrawTarget,
+    /// Data of the lines in the current frame
+    current: Vec<LineProgress>,
+    /// Message, progress step and detail last printed per id
+    printed: HashMap<u64, (String, Option<u64>, Option<String>)>,
+}
+
+impl AppendRenderer {
+    pub(crate) fn new(target: DrawTarget) -> Self {
+        Self {
+            target,
+            ..Self::default()
+        }
+    }
+
+    /// The progress step `line` is at, for determinate bars
+    fn step(line: &LineProgress) -> Option<u64> {
+        let (position, length) = (line.position?, line.length?);
+        let percent = match length {
+            0 => 100,
+            length => position.saturating_mul(100) / length,
+        };
+        Some(percent / APPEND_STEP_PERCENT)
+    }
+
+    fn write_lines(&mut self, lines: &[Vec<Segment>], done: bool) {
+        // A widget drawn on several lines is printed whole once anything about it changed
+        let mut changed = Vec::new();
+        for progress in &self.current {
+            if changed.contains(&progress.id) {
+                continue;
+            }
+            let seen = (
+                progress.message.clone(),
+                Self::step(progress),
+                progress.detail.clone(),
+            );
+            if self.printed.get(&progress.id) != Some(&seen) {
+                changed.push(progress.id);
+            }
+            if done {
+                self.printed.remove(&progress.id);
+            } else {
+                self.printed.insert(progress.id, seen);
+            }
+        }
+
+        let mut out = String::new();
+        for (index, line) in lines.iter().enumerate() {
+            let print = done
+                || self
+                    .current
+                    .get(index)
+                    .is_none_or(|progress| changed.contains(&progress.id));
+            if print {
+                let text: String = line.iter().map(|segment| segment.text.as_str()).collect();
+                let _ = writeln!(out, "{}", text.trim_end());
+            }
+        }
+        if done {
+            self.current.clear();
+        }
+
+        if !out.is_empty() {
+            let _ = self.target.write_all(out.as_bytes());
+        }
+    }
+}
+
+impl Renderer for AppendRenderer {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.write_lines(lines, false);
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.write_lines(lines, true);
+    }
+
+    // Nothing was left on screen, so there is nothing to take away
+    fn clear(&mut self) {
+        for progress in self.current.drain(..) {
+            self.printed.remove(&progress.id);
+        }
+    }
+
+    fn progress(&mut self, lines: &[LineProgress]) {
+        self.current = lines.to_vec();
+    }
+}
+
+// --- JSON Output ---
+
+/// Writes a JSON event per changed line instead of drawing
+#[derive(Default)]
+pub(crate) struct JsonRenderer {
+    target: DrawTarget,
+    /// Data of the lines in the current frame
+    current: Vec<LineProgress>,
+    /// Last data written per id, so repeated animation frames don't repeat events
+    written: HashMap<u64, LineProgress>,
+}
+
+impl JsonRenderer {
+    pub(crate) fn new(target: DrawTarget) -> Self {
+        Self {
+            target,
+            ..Self::default()
+        }
+    }
+
+    fn write_events(&mut self, done: bool) {
+        let mut out = String::new();
+
+        // Lines that dropped out of the frame, e.g. removed from a `MultiBar`, are done too
+        let mut gone: Vec<u64> = self
+            .written
+            .keys()
+            .filter(|id| !self.current.iter().any(|line| line.id == **id))
+            .copied()
+            .collect();
+        gone.sort_unstable();
+        for id in gone {
+            if let Some(line) = self.written.remove(&id) {
+                let _ = writeln!(out, "{}", json_event(&line, true));
+            }
+        }
+
+        let mut seen = Vec::new();
+        for line in &self.current {
+            // Lines after a widget's first repeat its data
+            if seen.contains(&line.id) {
+                continue;
+            }
+            seen.push(line.id);
+            if !done && self.written.get(&line.id) == Some(line) {
+                continue;
+            }
+            let _ = writeln!(out, "{}", json_event(line, done));
+            if done {
+                self.written.remove(&line.id);
+            } else {
+                self.written.insert(line.id, line.clone());
+            }
+        }
+
+        if !out.is_empty() {
+            let _ = self.target.write_all(out.as_bytes());
+        }
+        if done {
+            self.current.clear();
+        }
+    }
+}
+
+impl Renderer for JsonRenderer {
+    fn draw(&mut self, _lines: &[Vec<Segment>]) {
+        self.write_events(false);
+    }
+
+    fn finish(&mut self, _lines: &[Vec<Segment>]) {
+        self.write_events(true);
+    }
+
+    fn clear(&mut self) {
+        self.write_events(true);
+    }
+
+    fn progress(&mut self, lines: &[LineProgress]) {
+        self.current = lines.to_vec();
+    }
+}
+
+// --- Accessible Output ---
+
+/// Percent steps at which accessible mode announces a determinate bar
+const ANNOUNCE_STEP_PERCENT: u64 = 25;
+
+/// Announces progress as sentences, one per line, without any redrawing
+#[derive(Default)]
+pub(crate) struct AccessibleRenderer {
+    target: DrawTarget,
+    strings: Strings,
+    /// Data of the lines in the current frame
+    current: Vec<LineProgress>,
+    /// Message and progress step last announced per id
+    announced: HashMap<u64, (String, Option<u64>)>,
+}
+
+impl AccessibleRenderer {
+    pub(crate) fn new(target: DrawTarget, strings: Strings) -> Self {
+        Self {
+            target,
+            strings,
+            ..Self::default()
+        }
+    }
+
+    /// The percentage `line` is at, for determinate bars
+    fn percent(line: &LineProgress) -> Option<u64> {
+        let (position, length) = (line.position?, line.length?);
+        Some(match length {
+            0 => 100,
+            length => position.saturating_mul(100) / length,
+        })
+    }
+
+    fn announce(&mut self, done: bool) {
+        let mut out = String::new();
+        let mut seen = Vec::new();
+        for line in &self.current {
+            // Lines after a widget's first repeat its data
+            if seen.contains(&line.id) {
+                continue;
+            }
+            seen.push(line.id);
+
+            let percent = Self::percent(line);
+            if done {
+                self.announced.remove(&line.id);
+                let _ = writeln!(out, "{}", sentence(&line.message, &self.strings.finished));
+                continue;
+            }
+
+            let heard = (
+                line.message.clone(),
+                percent.map(|percent| percent / ANNOUNCE_STEP_PERCENT),
+            );
+            if self.announced.get(&line.id) == Some(&heard) {
+                continue;
+            }
+            let status = percent.map(|percent| {
+                self.strings
+                    .percent_complete
+                    .replace("{}", &percent.to_string())
+            });
+            let _ = match status {
+                Some(status) => writeln!(out, "{}", sentence(&line.message, &status)),
+                None if line.message.trim().is_empty() => Ok(()),
+                None => writeln!(out, "{}", line.message.trim()),
+            };
+            self.announced.insert(line.id, heard);
+        }
+        if done {
+            self.current.clear();
+        }
+
+        if !out.is_empty() {
+            let _ = self.target.write_all(out.as_bytes());
+        }
+    }
+}
+
+impl Renderer for AccessibleRenderer {
+    fn draw(&mut self, _lines: &[Vec<Segment>]) {
+        self.announce(false);
+    }
+
+    fn finish(&mut self, _lines: &[Vec<Segment>]) {
+        self.announce(true);
+    }
+
+    // A widget taken away without finishing has nothing left to announce
+    fn clear(&mut self) {
+        for line in self.current.drain(..) {
+            self.announced.remove(&line.id);
+        }
+    }
+
+    fn progress(&mut self, lines: &[LineProgress]) {
+        self.current = lines.to_vec();
+    }
+}
+
+/// `message: status`, or just the status without a message
+fn sentence(message: &str, status: &str) -> String {
+    match message.trim() {
+        "" => status.to_string(),
+        message => format!("{}: {}", message, status),
+    }
+}
+
+fn json_event(line: &LineProgress, done: bool) -> String {
+    let number = |value: Option<u64>| value.map_or("null".to_string(), |value| value.to_string());
+    let detail = match line.detail {
+        Some(ref detail) => format!(",\"detail\":{}", json_string(detail)),
+        None => String::new(),
+    };
+    format!(
+        "{{\"id\":{},\"pos\":{},\"len\":{},\"msg\":{}{},\"done\":{}}}",
+        line.id,
+        number(line.position),
+        number(line.length),
+        json_string(&line.message),
+        detail,
+        done
+    )
+}
+
+/// `text` as a quoted JSON string
+fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,118 @@
+//! A rendering-agnostic progress interface for library authors
+//!
+//! Libraries accept `&dyn ProgressReporter` (or `impl ProgressReporter`) and
+//! report through it; applications decide whether that ends up as a [`Bar`],
+//! a [`Throbber`], or nothing at all via [`NoopReporter`].
+
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use crate::{Bar, Status, Throbber};
+
+/// Boxed future returned by [`ProgressReporter`] methods, keeping the trait object safe
+pub type ReportFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// Minimal progress interface implemented by every widget
+pub trait ProgressReporter: Send + Sync {
+    /// Advance progress by `delta` units (ignored by widgets without a position)
+    fn inc(&self, delta: u64) -> ReportFuture<'_>;
+
+    /// Replace the message shown next to the progress
+    fn set_message(&self, msg: &str) -> ReportFuture<'_>;
+
+    /// Finish successfully
+    fn finish(&self) -> ReportFuture<'_>;
+}
+
+/// Reporter that discards everything, for callers that don't want progress output
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopReporter;
+
+impl ProgressReporter for NoopReporter {
+    fn inc(&self, _delta: u64) -> ReportFuture<'_> {
+        Box::pin(async {})
+    }
+
+    fn set_message(&self, _msg: &str) -> ReportFuture<'_> {
+        Box::pin(async {})
+    }
+
+    fn finish(&self) -> ReportFuture<'_> {
+        Box::pin(async {})
+    }
+}
+
+impl ProgressReporter for Bar {
+    fn inc(&self, delta: u64) -> ReportFuture<'_> {
+        Box::pin(Bar::inc(self, delta))
+    }
+
+    fn set_message(&self, msg: &str) -> ReportFuture<'_> {
+        Box::pin(Bar::set_message(self, msg.to_string()))
+    }
+
+    fn finish(&self) -> ReportFuture<'_> {
+        Box::pin(async move {
+            Bar::finish(self).await;
+        })
+    }
+}
+
+impl ProgressReporter for Throbber {
+    fn inc(&self, _delta: u64) -> ReportFuture<'_> {
+        Box::pin(async {})
+    }
+
+    fn set_message(&self, msg: &str) -> ReportFuture<'_> {
+        Box::pin(Throbber::set_message(self, msg.to_string()))
+    }
+
+    /// Stops with the success style, keeping the current message on the final line
+    fn finish(&self) -> ReportFuture<'_> {
+        Box::pin(async move {
+            let message = self.message().await;
+            self.stop_with_status(Status::Success, message).await;
+        })
+    }
+}
+
+impl<T: ProgressReporter + ?Sized> ProgressReporter for &T {
+    fn inc(&self, delta: u64) -> ReportFuture<'_> {
+        (**self).inc(delta)
+    }
+
+    fn set_message(&self, msg: &str) -> ReportFuture<'_> {
+        (**self).set_message(msg)
+    }
+
+    fn finish(&self) -> ReportFuture<'_> {
+        (**self).finish()
+    }
+}
+
+impl<T: ProgressReporter + ?Sized> ProgressReporter for Arc<T> {
+    fn inc(&self, delta: u64) -> ReportFuture<'_> {
+        (**self).inc(delta)
+    }
+
+    fn set_message(&self, msg: &str) -> ReportFuture<'_> {
+        (**self).set_message(msg)
+    }
+
+    fn finish(&self) -> ReportFuture<'_> {
+        (**self).finish()
+    }
+}
+
+impl<T: ProgressReporter + ?Sized> ProgressReporter for Box<T> {
+    fn inc(&self, delta: u64) -> ReportFuture<'_> {
+        (**self).inc(delta)
+    }
+
+    fn set_message(&self, msg: &str) -> ReportFuture<'_> {
+        (**self).set_message(msg)
+    }
+
+    fn finish(&self) -> ReportFuture<'_> {
+        (**self).finish()
+    }
+}
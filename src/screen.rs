@@ -0,0 +1,176 @@
+//! Printing above whatever widget is currently drawn
+//!
+//! Every widget write goes through [`write`], which remembers the last live
+//! frame and the stream it is on. [`suspend`] takes that frame off the screen,
+//! lets the caller write freely and then puts the frame back, so regular
+//! output scrolls up above the widgets instead of being overwritten by the
+//! next redraw.
+//!
+//! Every write also bumps a version, so a widget can tell whether the frame it
+//! drew last is still the latest thing on screen and redraw just what changed.
+
+use std::{
+    future::Future,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex as StdMutex,
+    },
+};
+
+use crossterm::{
+    cursor::MoveToColumn,
+    queue,
+    terminal::{Clear, ClearType},
+};
+
+use crate::DrawTarget;
+
+/// The frame currently on screen, with the cursor left at its first line
+struct LiveFrame {
+    bytes: Vec<u8>,
+    /// Stream of the latest widget write, where the cursor and colors live
+    target: DrawTarget,
+    /// Bumped by every write
+    version: u64,
+}
+
+static LIVE_FRAME: StdMutex<LiveFrame> = StdMutex::new(LiveFrame {
+    bytes: Vec::new(),
+    target: DrawTarget::Stderr,
+    version: 0,
+});
+
+/// Whether a widget pinned to the bottom has set a scroll region
+static SCROLL_REGION: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn set_scroll_region(active: bool) {
+    SCROLL_REGION.store(active, Ordering::Release);
+}
+
+/// Whether the terminal's scroll region needs resetting on cleanup
+pub(crate) fn scroll_region() -> bool {
+    SCROLL_REGION.load(Ordering::Acquire)
+}
+
+/// Whether a widget is drawing on the alternate screen
+static ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn set_alternate_screen(active: bool) {
+    ALTERNATE_SCREEN.store(active, Ordering::Release);
+}
+
+/// Whether the terminal needs switching back to the regular screen on cleanup
+pub(crate) fn alternate_screen() -> bool {
+    ALTERNATE_SCREEN.load(Ordering::Acquire)
+}
+
+/// How a write changes what is on screen
+#[derive(Clone, Copy)]
+pub(crate) enum FrameUpdate<'a> {
+    /// The write is a complete frame that later writes will redraw over
+    Live,
+    /// The write only redraws what changed since the screen was at `since`,
+    /// making it `frame`; `frame` is written instead if anything else was
+    /// written meanwhile
+    Changes { frame: &'a [u8], since: u64 },
+    /// The widget is done with its lines (final frame, cleared line, newline)
+    Done,
+    /// Cursor visibility and other writes that don't change the frame
+    Keep,
+}
+
+fn live_frame() -> std::sync::MutexGuard<'static, LiveFrame> {
+    LIVE_FRAME
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The stream widgets last wrote to, for terminal cleanup outside any widget
+pub(crate) fn target() -> DrawTarget {
+    live_frame().target
+}
+
+/// Writes and flushes a widget's output, keeping track of the live frame
+///
+/// Returns the screen's version after the write. Nothing is written for
+/// changes that are empty.
+pub(crate) fn write(target: DrawTarget, buf: &[u8], update: FrameUpdate) -> io::Result<u64> {
+    let mut frame = live_frame();
+    let buf = match update {
+        FrameUpdate::Changes { since, .. } if since == frame.version && buf.is_empty() => {
+            return Ok(frame.version);
+        }
+        FrameUpdate::Changes {
+            frame: whole,
+            since,
+        } if since != frame.version => whole,
+        _ => buf,
+    };
+    target.write_all(buf)?;
+    frame.target = target;
+    frame.version += 1;
+
+    match update {
+        FrameUpdate::Live => {
+            frame.bytes.clear();
+            frame.bytes.extend_from_slice(buf);
+        }
+        FrameUpdate::Changes { frame: whole, .. } => {
+            frame.bytes.clear();
+            frame.bytes.extend_from_slice(whole);
+        }
+        FrameUpdate::Done => frame.bytes.clear(),
+        FrameUpdate::Keep => {}
+    }
+    Ok(frame.version)
+}
+
+/// Clears the live frame, runs `f`, then draws the frame again below its output
+///
+/// Widgets can't redraw while `f` runs. `f` must not call [`suspend`] or
+/// [`println`] itself.
+pub fn suspend<R>(f: impl FnOnce() -> R) -> R {
+    let mut frame = live_frame();
+    take_down(&mut frame);
+    let result = f();
+    put_back(&mut frame);
+    result
+}
+
+/// Like [`suspend`] for a future, during which widgets aren't held back
+///
+/// Whatever frame is live once `fut` resolves is drawn again.
+pub(crate) async fn suspend_async<R>(fut: impl Future<Output = R>) -> R {
+    take_down(&mut live_frame());
+    let result = fut.await;
+    put_back(&mut live_frame());
+    result
+}
+
+/// Clears `frame` off the screen, leaving the cursor on its first line
+fn take_down(frame: &mut LiveFrame) {
+    if !frame.bytes.is_empty() {
+        let mut buf = Vec::new();
+        let _ = queue!(buf, MoveToColumn(0), Clear(ClearType::FromCursorDown));
+        let _ = frame.target.write_all(&buf);
+        frame.version += 1;
+    }
+}
+
+/// Draws `frame` again where it was taken down
+fn put_back(frame: &mut LiveFrame) {
+    if !frame.bytes.is_empty() {
+        let _ = frame.target.write_all(&frame.bytes);
+        frame.version += 1;
+    }
+}
+
+/// Prints a line above the widgets currently drawn, like [`std::println!`]
+pub fn println(line: impl AsRef<str>) {
+    suspend(|| {
+        let mut out = io::stdout().lock();
+        let _ = writeln!(out, "{}", line.as_ref());
+        let _ = out.flush();
+    });
+}
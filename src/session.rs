@@ -0,0 +1,182 @@
+//! Explicit, RAII-scoped setup of process-wide terminal state
+//!
+//! Widgets work without a [`Session`], but creating one up front gives an
+//! application a single place where capabilities are probed and terminal
+//! cleanup is guaranteed, instead of relying on whichever widget happens
+//! to touch that state first.
+
+use std::{
+    panic::{self, PanicHookInfo},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crossterm::{
+    cursor::{MoveToColumn, RestorePosition, SavePosition, Show},
+    queue,
+    style::{Print, ResetColor},
+    terminal::{Clear, ClearType, LeaveAlternateScreen},
+};
+use tokio::task::JoinHandle;
+
+use crate::{capabilities, screen, DrawTarget, OutputMode};
+
+type PanicHook = dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static;
+
+/// Sets up throbberous for the lifetime of the returned [`Session`]
+///
+/// Probes terminal capabilities eagerly (Unicode and color support, color
+/// depth, and the output mode `Auto` stands for on stdout and stderr), so
+/// drawing never has to, and installs a panic hook that restores the terminal
+/// before the panic message is printed. Dropping the session puts the previous
+/// panic hook back and restores the terminal again.
+pub fn init() -> Session {
+    capabilities::supports_unicode();
+    capabilities::color_depth();
+    for target in [DrawTarget::Stdout, DrawTarget::Stderr] {
+        capabilities::colors_enabled_for(target);
+        OutputMode::Auto.resolve_for(target);
+    }
+
+    let previous: Arc<PanicHook> = Arc::from(panic::take_hook());
+    chain_panic_hook(previous.clone());
+
+    Session {
+        previous_hook: Some(previous),
+        signal_task: None,
+    }
+}
+
+/// Installs, for the rest of the process, a panic hook that restores the terminal
+///
+/// For applications that can't keep a [`Session`] alive: colors are reset, the
+/// cursor shown and a fresh line started before the previously installed hook
+/// prints the panic message. Calling it again is a no-op.
+pub fn install_panic_hook() {
+    static INSTALLED: AtomicBool = AtomicBool::new(false);
+    if INSTALLED.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    chain_panic_hook(Arc::from(panic::take_hook()));
+}
+
+/// Restores the terminal, then hands the panic to `previous`
+fn chain_panic_hook(previous: Arc<PanicHook>) {
+    panic::set_hook(Box::new(move |info| {
+        restore_terminal(true);
+        previous(info);
+    }));
+}
+
+/// Guard returned by [`init`]; restores the terminal and panic hook on drop
+#[must_use = "the session is torn down as soon as it is dropped"]
+pub struct Session {
+    previous_hook: Option<Arc<PanicHook>>,
+    signal_task: Option<JoinHandle<()>>,
+}
+
+impl Session {
+    /// Cleans up the terminal and exits on Ctrl-C (and `SIGTERM` on Unix)
+    ///
+    /// The current line is cleared, colors reset and the cursor shown before
+    /// the process exits with the conventional `128 + signal` status. Only
+    /// covers signals received while the session is alive; must be called
+    /// from within a Tokio runtime.
+    pub fn handle_signals(mut self) -> Self {
+        self.signal_task = Some(tokio::spawn(async {
+            let code = wait_for_signal().await;
+            clear_terminal();
+            std::process::exit(code);
+        }));
+        self
+    }
+}
+
+/// Resolves with the exit code for the first termination signal received
+async fn wait_for_signal() -> i32 {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        if let Ok(mut terminate) = signal(SignalKind::terminate()) {
+            return tokio::select! {
+                _ = tokio::signal::ctrl_c() => 130,
+                _ = terminate.recv() => 143,
+            };
+        }
+    }
+
+    let _ = tokio::signal::ctrl_c().await;
+    130
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        if let Some(signal_task) = self.signal_task.take() {
+            signal_task.abort();
+        }
+        restore_terminal(false);
+
+        // Hooks can't be swapped while unwinding, the chained hook stays in place then
+        if let Some(previous) = self.previous_hook.take() {
+            if !std::thread::panicking() {
+                let _ = panic::take_hook();
+                panic::set_hook(Box::new(move |info| previous(info)));
+            }
+        }
+    }
+}
+
+/// Resets colors and shows the cursor, optionally moving to a fresh line
+pub(crate) fn restore_terminal(fresh_line: bool) {
+    let mut buf = Vec::new();
+    reset_scroll_region(&mut buf);
+    leave_alternate_screen(&mut buf);
+    let _ = queue!(buf, ResetColor, Show);
+    if fresh_line {
+        buf.push(b'\n');
+    }
+
+    write_terminal(&buf);
+}
+
+/// Erases the half-drawn line, then resets colors and shows the cursor
+fn clear_terminal() {
+    let mut buf = Vec::new();
+    reset_scroll_region(&mut buf);
+    leave_alternate_screen(&mut buf);
+    let _ = queue!(
+        buf,
+        MoveToColumn(0),
+        Clear(ClearType::CurrentLine),
+        ResetColor,
+        Show
+    );
+
+    write_terminal(&buf);
+}
+
+/// Gives the rows of a block pinned to the bottom back to regular output
+fn reset_scroll_region(buf: &mut Vec<u8>) {
+    if screen::scroll_region() {
+        // Resetting the region homes the cursor, so it is saved around it
+        let _ = queue!(buf, SavePosition, Print("\x1b[r"), RestorePosition);
+        screen::set_scroll_region(false);
+    }
+}
+
+/// Brings back the screen a dashboard on the alternate screen took over
+fn leave_alternate_screen(buf: &mut Vec<u8>) {
+    if screen::alternate_screen() {
+        let _ = queue!(buf, LeaveAlternateScreen);
+        screen::set_alternate_screen(false);
+    }
+}
+
+/// Writes to the stream widgets were drawn on
+fn write_terminal(buf: &[u8]) {
+    let _ = screen::target().write_all(buf);
+}
@@ -0,0 +1,398 @@
+//! A checklist of named steps worked through one after another, cargo-style
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex as StdMutex,
+    },
+    time::Duration,
+};
+
+use crossterm::style::Color;
+use tokio::{
+    sync::Mutex,
+    task::{self, JoinHandle},
+};
+
+use crate::{
+    cancel, capabilities, fit_text, registry, render, renderer, text_width, ticker, Frame, Layout,
+    LineProgress, RenderSignal, RenderStats, Segment, Status, Throbber, ThrobberConfig,
+};
+
+/// An ordered list of steps, the active one drawn with a spinner
+///
+/// ```text
+/// ✓ Fetching
+/// ⠹ Compiling
+///   Linking
+/// ```
+///
+/// Steps still pending are listed without a symbol; finished ones get the
+/// config's [`StatusTheme`](crate::StatusTheme) symbol. Spinner frames, colors
+/// and the renderer come from the [`ThrobberConfig`]. Data-oriented renderers
+/// see each step as a line of length 1, at position 0 while active and 1 once done.
+pub struct Steps {
+    inner: Arc<Mutex<StepsState>>,
+    notify: Arc<RenderSignal>,
+    closed: Arc<AtomicBool>,
+    draw_task: StdMutex<Option<JoinHandle<()>>>,
+    /// `None` with `manual_tick`
+    _animation: Option<ticker::Ticket>,
+    config: Arc<ThrobberConfig>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StepStatus {
+    Pending,
+    Active,
+    Done(Status),
+}
+
+struct Step {
+    /// Identifies the step's line to data-oriented renderers
+    id: u64,
+    name: String,
+    status: StepStatus,
+}
+
+struct StepsState {
+    steps: Vec<Step>,
+    frame_index: usize,
+    /// Animation ticks, fed to a custom animator
+    tick: u64,
+    color_index: usize,
+    registration: Option<registry::Registration>,
+}
+
+impl StepsState {
+    fn active(&mut self) -> Option<&mut Step> {
+        self.steps
+            .iter_mut()
+            .find(|step| step.status == StepStatus::Active)
+    }
+
+    /// Marks the active step, if any, as done with `status`
+    fn complete(&mut self, status: Status) {
+        if let Some(step) = self.active() {
+            step.status = StepStatus::Done(status);
+        }
+    }
+}
+
+impl Steps {
+    /// Declares the steps, none of them started yet
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::with_config(names, ThrobberConfig::default())
+    }
+
+    /// Declares the steps, drawn without colors
+    pub fn new_plain<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::with_config(names, ThrobberConfig::no_colors())
+    }
+
+    pub fn with_config<I, S>(names: I, config: ThrobberConfig) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let state = StepsState {
+            steps: names
+                .into_iter()
+                .map(|name| Step {
+                    id: render::next_id(),
+                    name: name.into(),
+                    status: StepStatus::Pending,
+                })
+                .collect(),
+            frame_index: 0,
+            tick: 0,
+            color_index: 0,
+            registration: None,
+        };
+
+        let inner = Arc::new(Mutex::new(state));
+        let notify = Arc::new(RenderSignal::new());
+        let closed = Arc::new(AtomicBool::new(false));
+        let config = Arc::new(config);
+
+        let draw_task = Self::spawn_draw_task(
+            inner.clone(),
+            notify.clone(),
+            closed.clone(),
+            config.clone(),
+        );
+        let animation = (!config.manual_tick).then(|| {
+            Self::animate(
+                inner.clone(),
+                notify.clone(),
+                closed.clone(),
+                config.clone(),
+            )
+        });
+
+        Steps {
+            inner,
+            notify,
+            closed,
+            draw_task: StdMutex::new(Some(draw_task)),
+            _animation: animation,
+            config,
+        }
+    }
+
+    fn spawn_draw_task(
+        inner: Arc<Mutex<StepsState>>,
+        notify: Arc<RenderSignal>,
+        closed: Arc<AtomicBool>,
+        config: Arc<ThrobberConfig>,
+    ) -> JoinHandle<()> {
+        task::spawn(async move {
+            let mut renderer = renderer(
+                config.renderer.as_ref(),
+                config.output,
+                config.target,
+                config.color_choice,
+                config.hide_cursor,
+                Layout::Inline,
+                config.on_error.clone(),
+                &config.strings,
+            );
+
+            loop {
+                let wake = cancel::wait(&notify, config.cancel_token()).await;
+                let started = std::time::Instant::now();
+                let mut state = inner.lock().await;
+
+                if matches!(wake, cancel::Wake::Cancelled) {
+                    closed.store(true, Ordering::Release);
+                    state.complete(Status::Cancelled);
+                }
+
+                let frame = Self::frame(&state, &config);
+                if closed.load(Ordering::Acquire) {
+                    state.registration = None;
+                    frame.finish(renderer.as_mut());
+                    notify.record_draw(started);
+                    break;
+                }
+
+                frame.draw(renderer.as_mut());
+                notify.record_draw(started);
+            }
+        })
+    }
+
+    fn animate(
+        inner: Arc<Mutex<StepsState>>,
+        notify: Arc<RenderSignal>,
+        closed: Arc<AtomicBool>,
+        config: Arc<ThrobberConfig>,
+    ) -> ticker::Ticket {
+        ticker::register(Duration::from_millis(config.frame_delay), move || {
+            if closed.load(Ordering::Acquire) {
+                return false;
+            }
+
+            // Skips the tick while the checklist is being drawn
+            if let Ok(mut state) = inner.try_lock() {
+                if Self::advance(&mut state, &config) {
+                    drop(state);
+                    notify.notify_one();
+                }
+            }
+            true
+        })
+    }
+
+    /// Moves the active step's spinner one frame on; returns whether a step is active
+    fn advance(state: &mut StepsState, config: &ThrobberConfig) -> bool {
+        if state.active().is_none() {
+            return false;
+        }
+        state.frame_index = (state.frame_index + 1) % config.frames.len().max(1);
+        state.tick += 1;
+        if let Some(ref colors) = config.colors {
+            if !colors.is_empty() {
+                state.color_index = (state.color_index + 1) % colors.len();
+            }
+        }
+        true
+    }
+
+    /// Moves the active step's spinner one frame on and redraws the checklist once
+    ///
+    /// For checklists with [`ThrobberConfig::manual_tick`], driven by the
+    /// caller's own loop instead of a background timer.
+    pub async fn tick(&self) {
+        if self.closed.load(Ordering::Acquire) {
+            return;
+        }
+        if Self::advance(&mut *self.inner.lock().await, &self.config) {
+            self.notify.notify_one();
+        }
+    }
+
+    /// Marks the active step done and starts the step called `name`
+    ///
+    /// Starts the first declared step of that name that hasn't run yet, so
+    /// declared steps jumped over stay pending. A name that wasn't declared is
+    /// added right after the step it follows.
+    pub async fn next(&self, name: impl Into<String>) {
+        let name = name.into();
+        {
+            let mut state = self.inner.lock().await;
+            // Steps after the last one that ran are still to come
+            let upcoming = state
+                .steps
+                .iter()
+                .rposition(|step| step.status != StepStatus::Pending)
+                .map_or(0, |index| index + 1);
+            state.complete(Status::Success);
+
+            let declared = state.steps[upcoming..]
+                .iter()
+                .position(|step| step.name == name)
+                .map(|index| upcoming + index);
+            match declared {
+                Some(index) => state.steps[index].status = StepStatus::Active,
+                None => state.steps.insert(
+                    upcoming,
+                    Step {
+                        id: render::next_id(),
+                        name,
+                        status: StepStatus::Active,
+                    },
+                ),
+            }
+
+            if state.registration.is_none() {
+                state.registration = Some(registry::register(
+                    "Steps",
+                    self.config.output.resolve_for(self.config.target),
+                ));
+            }
+        }
+        self.notify.notify_one();
+    }
+
+    /// Marks the active step done and leaves the checklist on screen
+    ///
+    /// Resolves once the final frame has been written.
+    pub async fn finish(&self) {
+        self.finish_with_status(Status::Success).await;
+    }
+
+    /// Marks the active step failed and leaves the checklist on screen
+    ///
+    /// Resolves once the final frame has been written.
+    pub async fn fail(&self) {
+        self.finish_with_status(Status::Error).await;
+    }
+
+    /// Ends the active step with `status` and leaves the checklist on screen
+    pub async fn finish_with_status(&self, status: Status) {
+        self.inner.lock().await.complete(status);
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+
+        let draw_task = self
+            .draw_task
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(draw_task) = draw_task {
+            let _ = draw_task.await;
+        }
+    }
+
+    /// Rendering performance counters collected by the draw task
+    pub fn render_stats(&self) -> RenderStats {
+        self.notify.stats()
+    }
+
+    /// One line per step
+    fn frame(state: &StepsState, config: &ThrobberConfig) -> Frame {
+        let spinner = Throbber::spinner(&config.frames, state.frame_index, state.tick, config);
+        let columns = config
+            .fit_to_terminal
+            .then(capabilities::terminal_width)
+            .flatten();
+        let pending_color = config.colors.as_ref().map(|_| Color::DarkGrey);
+
+        let mut frame = Frame::default();
+        for step in &state.steps {
+            let (symbol, symbol_color, name_color, position) = match step.status {
+                StepStatus::Pending => {
+                    (" ".repeat(text_width(&spinner)), None, pending_color, None)
+                }
+                StepStatus::Active => {
+                    let color = config
+                        .theme
+                        .bar
+                        .or(Throbber::cycle_color(config, state.color_index));
+                    (
+                        spinner.to_string(),
+                        color,
+                        config.theme.message.or(color),
+                        Some(0),
+                    )
+                }
+                StepStatus::Done(status) => {
+                    let color = config.theme.status_color(status, &config.status_theme);
+                    (
+                        config.status_theme.style(status).symbol.clone(),
+                        color,
+                        color,
+                        Some(1),
+                    )
+                }
+            };
+
+            let name = fit_text(
+                &step.name,
+                columns,
+                text_width(&symbol) + 1,
+                config.truncation,
+                config.charset,
+            );
+            frame.push(
+                vec![
+                    Segment::new(symbol, symbol_color),
+                    Segment::new(" ", None),
+                    Segment::new(name, name_color).with_style(config.theme.message_style),
+                ],
+                LineProgress {
+                    id: step.id,
+                    position,
+                    length: Some(1),
+                    message: step.name.clone(),
+                    detail: None,
+                },
+            );
+        }
+        frame
+    }
+}
+
+impl Drop for Steps {
+    // Like an unfinished `MultiBar`, the checklist is left as last drawn
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::Release);
+        let draw_task = self
+            .draw_task
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(draw_task) = draw_task {
+            draw_task.abort();
+        }
+    }
+}
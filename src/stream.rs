@@ -0,0 +1,113 @@
+//! Progress for async streams, e.g. HTTP response bodies
+
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures_core::Stream;
+
+use crate::{Bar, BarConfig};
+
+/// Progress an item of a [`ProgressStream`] is worth
+type Weigh<T> = Box<dyn Fn(&T) -> u64 + Send + Sync>;
+
+/// A stream advancing a bar by each item it yields, see [`Bar::wrap_sized_stream`]
+///
+/// Items count 1 each unless weighed with [`ProgressStream::weigh_items`].
+/// The bar finishes once the stream ends.
+///
+/// ```rust,ignore
+/// let length = response.content_length();
+/// let mut body = Bar::wrap_stream_with_total(response.bytes_stream(), length, config)
+///     .weigh_items(|chunk| chunk.as_ref().map_or(0, |bytes| bytes.len() as u64));
+/// while let Some(chunk) = body.next().await {
+///     file.write_all(&chunk?).await?;
+/// }
+/// ```
+pub struct ProgressStream<S: Stream> {
+    stream: Pin<Box<S>>,
+    bar: Bar,
+    /// Progress each item is worth, 1 when unset
+    weigh: Option<Weigh<S::Item>>,
+}
+
+impl<S: Stream> ProgressStream<S> {
+    /// Wraps `stream`, advancing `bar`
+    pub fn new(stream: S, bar: Bar) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            bar,
+            weigh: None,
+        }
+    }
+
+    /// Advances the bar by `weigh(item)` per item, e.g. the length of a chunk of bytes
+    pub fn weigh_items(mut self, weigh: impl Fn(&S::Item) -> u64 + Send + Sync + 'static) -> Self {
+        self.weigh = Some(Box::new(weigh));
+        self
+    }
+
+    /// The bar the stream advances
+    pub fn bar(&self) -> &Bar {
+        &self.bar
+    }
+
+    /// The wrapped stream, positioned after the last item yielded
+    pub fn into_inner(self) -> Pin<Box<S>> {
+        self.stream
+    }
+}
+
+impl<S: Stream> Stream for ProgressStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let this = self.get_mut();
+        let item = ready!(this.stream.as_mut().poll_next(cx));
+        match item {
+            Some(ref item) => {
+                let delta = this.weigh.as_ref().map_or(1, |weigh| weigh(item));
+                this.bar.counters.inc(delta);
+            }
+            None => this.bar.complete(),
+        }
+        this.bar.notify.notify_one();
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl Bar {
+    /// Wraps `stream` in a bar counting its items
+    ///
+    /// The bar is determinate when the stream knows exactly how many items it
+    /// has left, from its `size_hint`, and indeterminate otherwise.
+    pub fn wrap_sized_stream<S: Stream>(stream: S) -> ProgressStream<S> {
+        let total = match stream.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower as u64),
+            _ => None,
+        };
+        Self::wrap_stream_with_total(stream, total, BarConfig::default())
+    }
+
+    /// Wraps `stream` in a bar of `total`, e.g. an HTTP `Content-Length`, or
+    /// an indeterminate one without it
+    ///
+    /// Pair a total in bytes with [`ProgressStream::weigh_items`] and
+    /// `Units::Bytes` in `config`.
+    pub fn wrap_stream_with_total<S: Stream>(
+        stream: S,
+        total: Option<u64>,
+        config: BarConfig,
+    ) -> ProgressStream<S> {
+        let bar = match total {
+            Some(total) => Self::with_config(total, config),
+            None => Self::indeterminate_with_config(String::new(), config),
+        };
+        ProgressStream::new(stream, bar)
+    }
+}
@@ -0,0 +1,117 @@
+//! Built-in text, replaceable for CLIs that aren't in English
+//!
+//! Everything a widget writes on its own, as opposed to the messages the
+//! caller sets, comes from the config's [`Strings`]. Status symbols (`✓`,
+//! `✗`, ...) are set by [`StatusTheme`](crate::StatusTheme) instead.
+
+/// The words widgets put on screen by themselves
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use throbberous::{BarConfig, Strings};
+///
+/// let strings = Strings {
+///     eta: "restant".to_string(),
+///     complete: "Terminé !".to_string(),
+///     ..Strings::default()
+/// };
+/// let milestones = strings.clone();
+/// let config = BarConfig::builder()
+///     .strings(strings)
+///     .message_provider(Some(Arc::new(move |progress| {
+///         milestones.milestone(progress).to_string()
+///     })))
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Strings {
+    /// A throbber's message until one is set
+    pub throbbing: String,
+    /// Milestone messages by quarter, see [`Strings::milestone`]
+    pub working: String,
+    pub quarter_done: String,
+    pub halfway_done: String,
+    pub almost_there: String,
+    pub complete: String,
+    /// Before the time left, `ETA 27s`
+    pub eta: String,
+    /// Before a finished bar's average rate, `avg 29.0 MiB/s`
+    pub average: String,
+    /// After the counts of a bar splitting items by outcome, `40 ok, 2 failed`
+    pub ok: String,
+    pub failed: String,
+    /// A task queue's summary, `6/7 tasks succeeded, 1 failed: ..., 1 skipped`
+    pub tasks_succeeded: String,
+    pub skipped: String,
+    /// A download's summary, `ubuntu.iso: fetched 1.2 GiB in 42s`
+    pub fetched: String,
+    pub fetched_in: String,
+    /// Accessible mode's announcements, `Build: finished` and `Build: 50 percent
+    /// complete`; `{}` in `percent_complete` is replaced with the percentage
+    pub finished: String,
+    pub percent_complete: String,
+    /// Column headers of a [`Summary`](crate::Summary) table
+    pub column_id: String,
+    pub column_widget: String,
+    pub column_status: String,
+    pub column_progress: String,
+    pub column_time: String,
+    /// How a widget in a [`Summary`](crate::Summary) table ended, or that it hasn't
+    pub status_success: String,
+    pub status_warning: String,
+    pub status_error: String,
+    pub status_cancelled: String,
+    pub status_timeout: String,
+    pub status_stopped: String,
+    pub status_running: String,
+}
+
+impl Default for Strings {
+    fn default() -> Self {
+        Self {
+            throbbing: "Throbbing...".to_string(),
+            working: "Working...".to_string(),
+            quarter_done: "Quarter done".to_string(),
+            halfway_done: "Halfway done".to_string(),
+            almost_there: "Almost there...".to_string(),
+            complete: "Complete!".to_string(),
+            eta: "ETA".to_string(),
+            average: "avg".to_string(),
+            ok: "ok".to_string(),
+            failed: "failed".to_string(),
+            tasks_succeeded: "tasks succeeded".to_string(),
+            skipped: "skipped".to_string(),
+            fetched: "fetched".to_string(),
+            fetched_in: "in".to_string(),
+            finished: "finished".to_string(),
+            percent_complete: "{} percent complete".to_string(),
+            column_id: "ID".to_string(),
+            column_widget: "WIDGET".to_string(),
+            column_status: "STATUS".to_string(),
+            column_progress: "PROGRESS".to_string(),
+            column_time: "TIME".to_string(),
+            status_success: "success".to_string(),
+            status_warning: "warning".to_string(),
+            status_error: "error".to_string(),
+            status_cancelled: "cancelled".to_string(),
+            status_timeout: "timeout".to_string(),
+            status_stopped: "stopped".to_string(),
+            status_running: "running".to_string(),
+        }
+    }
+}
+
+impl Strings {
+    /// The milestone message for `progress`, by quarters from `working` to `complete`
+    pub fn milestone(&self, progress: f64) -> &str {
+        match progress {
+            p if p >= 1.0 => &self.complete,
+            p if p >= 0.75 => &self.almost_there,
+            p if p >= 0.5 => &self.halfway_done,
+            p if p >= 0.25 => &self.quarter_done,
+            _ => &self.working,
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! How every widget of a run went, collected without tracking them by hand
+//!
+//! Once [`collect_summary`] is called, each bar and throbber created
+//! afterwards is kept track of; [`summary`] reads how far each got and how it
+//! ended, e.g. for a closing line at the end of the program.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex as StdMutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Mutex;
+
+use crate::{format, text_width, BarCounters, BarState, Status, Strings, ThrobberState};
+
+static COLLECTING: AtomicBool = AtomicBool::new(false);
+static TRACKED: StdMutex<Vec<Tracked>> = StdMutex::new(Vec::new());
+
+/// A widget created while collecting, read again for every [`summary`]
+#[derive(Clone)]
+struct Tracked {
+    label: Option<String>,
+    started: Instant,
+    widget: Widget,
+}
+
+#[derive(Clone)]
+enum Widget {
+    Bar {
+        counters: Arc<BarCounters>,
+        state: Arc<Mutex<BarState>>,
+    },
+    Throbber(Arc<Mutex<ThrobberState>>),
+}
+
+/// How one bar or throbber went, see [`Summary`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WidgetSummary {
+    /// Same as [`Bar::id`](crate::Bar::id) / [`Throbber::id`](crate::Throbber::id)
+    pub id: u64,
+    /// The config's `label`, or the widget's message without one
+    pub label: String,
+    /// `None` while the widget runs, and for throbbers stopped without an outcome
+    pub status: Option<Status>,
+    /// Where a bar got to; `None` for throbbers
+    pub position: Option<u64>,
+    /// `None` unless the widget is a determinate bar
+    pub total: Option<u64>,
+    /// When the widget was created
+    pub started: Instant,
+    /// When the widget finished or stopped, `None` while it runs
+    pub finished: Option<Instant>,
+}
+
+impl WidgetSummary {
+    /// Time from creation until the widget finished, or until now if it hasn't
+    pub fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(Instant::now) - self.started
+    }
+}
+
+/// Every widget created since [`collect_summary`], in creation order
+///
+/// Printed with `{}`, it is a table of one widget per line:
+///
+/// ```text
+///  ID  WIDGET     STATUS   PROGRESS  TIME
+///   1  download   success  100/100   12s
+///   2  checksum   error    40/100    3s
+/// ```
+///
+/// The headers and status names come from the default [`Strings`], see
+/// [`Summary::table`] for others.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub widgets: Vec<WidgetSummary>,
+}
+
+impl Summary {
+    /// Number of widgets that ended with `status`
+    pub fn count(&self, status: Status) -> usize {
+        self.widgets
+            .iter()
+            .filter(|widget| widget.status == Some(status))
+            .count()
+    }
+
+    /// Number of widgets that finished or stopped, whatever their outcome
+    pub fn finished(&self) -> usize {
+        self.widgets
+            .iter()
+            .filter(|widget| widget.finished.is_some())
+            .count()
+    }
+
+    /// Time from the first widget's creation until the last one finished
+    /// (or until now, while any is still running)
+    pub fn elapsed(&self) -> Duration {
+        let started = self.widgets.iter().map(|widget| widget.started).min();
+        let finished = self
+            .widgets
+            .iter()
+            .map(|widget| widget.finished.unwrap_or_else(Instant::now))
+            .max();
+        match (started, finished) {
+            (Some(started), Some(finished)) => finished - started,
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// The table `{}` prints, with headers and status names from `strings`
+    pub fn table<'a>(&'a self, strings: &'a Strings) -> SummaryTable<'a> {
+        SummaryTable {
+            summary: self,
+            strings,
+        }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.table(&Strings::default()).fmt(f)
+    }
+}
+
+/// A [`Summary`] printed in other words, see [`Summary::table`]
+pub struct SummaryTable<'a> {
+    summary: &'a Summary,
+    strings: &'a Strings,
+}
+
+impl fmt::Display for SummaryTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let strings = self.strings;
+        let header = [
+            &strings.column_id,
+            &strings.column_widget,
+            &strings.column_status,
+            &strings.column_progress,
+            &strings.column_time,
+        ]
+        .map(String::clone);
+        let rows: Vec<[String; 5]> = self
+            .summary
+            .widgets
+            .iter()
+            .map(|widget| {
+                [
+                    widget.id.to_string(),
+                    widget.label.clone(),
+                    status_name(widget, strings).to_string(),
+                    match (widget.position, widget.total) {
+                        (Some(position), Some(total)) => format!("{}/{}", position, total),
+                        (Some(position), None) => position.to_string(),
+                        (None, _) => "-".to_string(),
+                    },
+                    format::duration(widget.elapsed()),
+                ]
+            })
+            .collect();
+
+        let mut widths = [0; 5];
+        for row in std::iter::once(&header).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(text_width(cell));
+            }
+        }
+
+        for row in std::iter::once(&header).chain(&rows) {
+            let mut line = String::new();
+            for (column, (cell, width)) in row.iter().zip(widths).enumerate() {
+                let padding = " ".repeat(width - text_width(cell));
+                // Ids line up on the right, everything else on the left
+                match column {
+                    0 => line += &format!(" {}{}", padding, cell),
+                    _ => line += &format!("  {}{}", cell, padding),
+                }
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+fn status_name<'a>(widget: &WidgetSummary, strings: &'a Strings) -> &'a str {
+    match widget.status {
+        Some(Status::Success) => &strings.status_success,
+        Some(Status::Warning) => &strings.status_warning,
+        Some(Status::Error) => &strings.status_error,
+        Some(Status::Cancelled) => &strings.status_cancelled,
+        Some(Status::Timeout) => &strings.status_timeout,
+        None if widget.finished.is_some() => &strings.status_stopped,
+        None => &strings.status_running,
+    }
+}
+
+/// Starts keeping track of every bar and throbber created from now on, for [`summary`]
+///
+/// Widgets stay tracked for the rest of the process, so this is meant for
+/// runs with a known end rather than long-lived services.
+pub fn collect_summary() {
+    COLLECTING.store(true, Ordering::Release);
+}
+
+/// How every widget created since [`collect_summary`] went so far
+///
+/// ```rust
+/// use throbberous::{format, Bar, Status};
+///
+/// # tokio_test::block_on(async {
+/// throbberous::collect_summary();
+/// let bar = Bar::new_plain(10);
+/// bar.finish_with_status(Status::Error, "disk full").await;
+///
+/// let summary = throbberous::summary().await;
+/// println!(
+///     "completed {} tasks, {} failed, total {}",
+///     summary.finished(),
+///     summary.count(Status::Error),
+///     format::duration(summary.elapsed())
+/// );
+/// print!("{}", summary);
+/// # });
+/// ```
+pub async fn summary() -> Summary {
+    // Copied out, the widgets' state locks can't be awaited while holding this one
+    let tracked = lock_tracked().clone();
+
+    let mut summary = Summary::default();
+    for Tracked {
+        label,
+        started,
+        widget,
+    } in tracked
+    {
+        let widget = match widget {
+            Widget::Bar { counters, state } => {
+                let state = state.lock().await;
+                let finished = counters.finished_at.get().copied();
+                WidgetSummary {
+                    id: counters.id,
+                    label: label.unwrap_or_else(|| state.message.clone()),
+                    status: finished.map(|_| state.status.unwrap_or(Status::Success)),
+                    position: Some(counters.current.load(Ordering::Acquire)),
+                    total: counters
+                        .determinate
+                        .load(Ordering::Acquire)
+                        .then(|| counters.total.load(Ordering::Relaxed)),
+                    started,
+                    finished,
+                }
+            }
+            Widget::Throbber(state) => {
+                let state = state.lock().await;
+                WidgetSummary {
+                    id: state.id,
+                    label: label.unwrap_or_else(|| state.message.clone()),
+                    status: state.outcome,
+                    position: None,
+                    total: None,
+                    started,
+                    finished: state.stopped,
+                }
+            }
+        };
+        summary.widgets.push(widget);
+    }
+    summary
+}
+
+fn lock_tracked() -> MutexGuard<'static, Vec<Tracked>> {
+    TRACKED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn track(label: Option<&String>, widget: Widget) {
+    if COLLECTING.load(Ordering::Acquire) {
+        lock_tracked().push(Tracked {
+            label: label.cloned(),
+            started: Instant::now(),
+            widget,
+        });
+    }
+}
+
+pub(crate) fn track_bar(
+    label: Option<&String>,
+    counters: &Arc<BarCounters>,
+    state: &Arc<Mutex<BarState>>,
+) {
+    track(
+        label,
+        Widget::Bar {
+            counters: counters.clone(),
+            state: state.clone(),
+        },
+    );
+}
+
+pub(crate) fn track_throbber(label: Option<&String>, state: &Arc<Mutex<ThrobberState>>) {
+    track(label, Widget::Throbber(state.clone()));
+}
@@ -0,0 +1,111 @@
+//! A renderer that captures frames as plain text, for tests of code using widgets
+
+use std::sync::{Arc, Mutex as StdMutex};
+
+use crate::{Renderer, RendererFactory, Segment};
+
+/// Records every frame drawn by the widgets built with [`TestRenderer::factory`]
+///
+/// Frames are kept as plain text without colors, one string per frame with
+/// its lines joined by `\n`. A cleared frame is recorded as an empty string.
+/// Draws happen on the widgets' background tasks, so assert after awaiting
+/// `finish`/`stop` (or after giving the draw task a moment to run).
+///
+/// ```rust
+/// use throbberous::{Bar, BarConfig, TestRenderer};
+/// # tokio_test::block_on(async {
+/// let output = TestRenderer::new();
+/// let config = BarConfig::builder()
+///     .colors(None)
+///     .renderer(Some(output.factory()))
+///     .fit_to_terminal(false)
+///     .build();
+/// let bar = Bar::with_config(100, config);
+/// bar.inc(50).await;
+/// bar.finish().await;
+/// assert!(output.last_frame().unwrap().contains("100%"));
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TestRenderer {
+    frames: Arc<StdMutex<Vec<String>>>,
+    finished: Arc<StdMutex<bool>>,
+}
+
+impl TestRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Factory for `BarConfig::renderer` / `ThrobberConfig::renderer`
+    ///
+    /// Every widget built from it records into this renderer.
+    pub fn factory(&self) -> RendererFactory {
+        let renderer = self.clone();
+        Arc::new(move || Box::new(renderer.clone()))
+    }
+
+    /// Every frame recorded so far, oldest first
+    pub fn frames(&self) -> Vec<String> {
+        self.lock_frames().clone()
+    }
+
+    /// The most recent frame, if anything was drawn
+    pub fn last_frame(&self) -> Option<String> {
+        self.lock_frames().last().cloned()
+    }
+
+    /// Whether the last frame ended its block, i.e. was finished or cleared
+    pub fn is_finished(&self) -> bool {
+        *self
+            .finished
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Forgets the frames recorded so far
+    pub fn clear_frames(&self) {
+        self.lock_frames().clear();
+    }
+
+    fn record(&mut self, frame: String, finished: bool) {
+        self.lock_frames().push(frame);
+        *self
+            .finished
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = finished;
+    }
+
+    fn lock_frames(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.frames
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// The plain text of `lines`
+fn plain(lines: &[Vec<Segment>]) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|segment| segment.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Renderer for TestRenderer {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.record(plain(lines), false);
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.record(plain(lines), true);
+    }
+
+    fn clear(&mut self) {
+        self.record(String::new(), true);
+    }
+}
@@ -0,0 +1,188 @@
+//! Per-part color themes
+//!
+//! A [`Theme`] colors the parts of a widget's line independently. Any part left
+//! as `None` inherits the line color, which comes from the color cycle or
+//! gradient, so the default theme keeps the classic single-color look.
+//! Colors can be named, `Color::AnsiValue` (256-color) or `Color::Rgb`.
+//! On top of its color, a part can get a background and attributes from a
+//! [`TextStyle`].
+
+use crossterm::style::{Attribute, Attributes, Color};
+
+use crate::{Status, StatusTheme};
+
+/// Colors and styles for the parts of a widget's line
+///
+/// Setting `message` keeps the message steady while the bar keeps cycling
+/// through its colors:
+///
+/// ```rust
+/// use crossterm::style::Color;
+/// use throbberous::{BarConfig, TextStyle, Theme};
+///
+/// let config = BarConfig::builder()
+///     .theme(Theme {
+///         message: Some(Color::White),
+///         message_style: TextStyle {
+///             dim: true,
+///             ..TextStyle::default()
+///         },
+///         ..Theme::default()
+///     })
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Theme {
+    /// The bar cells and percentage, or the spinner glyph
+    pub bar: Option<Color>,
+    /// The message text; `None` follows the color cycle along with the bar
+    pub message: Option<Color>,
+    /// The brackets around the bar
+    pub brackets: Option<Color>,
+    /// The final line of a successfully finished widget
+    pub finish: Option<Color>,
+    /// The final line of a widget finished with an error
+    pub error: Option<Color>,
+    /// Background and attributes of the filled bar cells, or the spinner glyph
+    pub filled_style: TextStyle,
+    /// Background and attributes of the empty bar cells
+    pub empty_style: TextStyle,
+    /// Background and attributes of the message text
+    pub message_style: TextStyle,
+}
+
+/// Background color and attributes of a run of text, on top of its color
+///
+/// Like colors, these are only emitted when the widget draws in color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TextStyle {
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+}
+
+impl TextStyle {
+    /// Whether the style changes anything about how text is drawn
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub(crate) fn attributes(&self) -> Attributes {
+        let mut attributes = Attributes::default();
+        for (set, attribute) in [
+            (self.bold, Attribute::Bold),
+            (self.dim, Attribute::Dim),
+            (self.italic, Attribute::Italic),
+        ] {
+            if set {
+                attributes.set(attribute);
+            }
+        }
+        attributes
+    }
+}
+
+impl Theme {
+    /// Shades of grey only
+    pub fn monochrome() -> Self {
+        Self {
+            bar: Some(Color::White),
+            message: Some(Color::Grey),
+            brackets: Some(Color::DarkGrey),
+            finish: Some(Color::White),
+            error: Some(Color::White),
+            ..Self::default()
+        }
+    }
+
+    /// Blues and teals (256-color)
+    pub fn ocean() -> Self {
+        Self {
+            bar: Some(Color::AnsiValue(39)),
+            message: Some(Color::AnsiValue(152)),
+            brackets: Some(Color::AnsiValue(24)),
+            finish: Some(Color::AnsiValue(43)),
+            error: Some(Color::AnsiValue(203)),
+            ..Self::default()
+        }
+    }
+
+    /// Greens and browns (truecolor)
+    pub fn forest() -> Self {
+        Self {
+            bar: Some(Color::Rgb {
+                r: 95,
+                g: 175,
+                b: 95,
+            }),
+            message: Some(Color::Rgb {
+                r: 215,
+                g: 215,
+                b: 175,
+            }),
+            brackets: Some(Color::Rgb {
+                r: 135,
+                g: 95,
+                b: 55,
+            }),
+            finish: Some(Color::Rgb {
+                r: 135,
+                g: 215,
+                b: 95,
+            }),
+            error: Some(Color::Rgb {
+                r: 215,
+                g: 95,
+                b: 55,
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Oranges and purples (truecolor)
+    pub fn sunset() -> Self {
+        Self {
+            bar: Some(Color::Rgb {
+                r: 255,
+                g: 135,
+                b: 0,
+            }),
+            message: Some(Color::Rgb {
+                r: 255,
+                g: 215,
+                b: 175,
+            }),
+            brackets: Some(Color::Rgb {
+                r: 135,
+                g: 95,
+                b: 175,
+            }),
+            finish: Some(Color::Rgb {
+                r: 255,
+                g: 175,
+                b: 95,
+            }),
+            error: Some(Color::Rgb {
+                r: 215,
+                g: 0,
+                b: 95,
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Color of a finished line, preferring the theme over the status theme
+    pub(crate) fn status_color(&self, status: Status, status_theme: &StatusTheme) -> Option<Color> {
+        let themed = match status {
+            Status::Success => self.finish,
+            Status::Error => self.error,
+            Status::Warning | Status::Cancelled | Status::Timeout => None,
+        };
+        themed.or(status_theme.style(status).color)
+    }
+}
@@ -0,0 +1,134 @@
+//! One background thread moving every widget's animation on
+//!
+//! Spinners, indeterminate bars and counters need a regular tick. Instead of
+//! a task with its own timer per widget, each registers a callback here and a
+//! single thread, started with the first one, calls whichever are due. The
+//! callbacks only touch the widget's state and wake its draw task, so they
+//! don't depend on the runtime the widget was created on.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex as StdMutex, MutexGuard, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Called once per interval; returns `false` once there is nothing left to animate
+type Tick = Box<dyn FnMut() -> bool + Send>;
+
+struct Entry {
+    interval: Duration,
+    due: Instant,
+    cancelled: Arc<AtomicBool>,
+    tick: Tick,
+}
+
+struct Ticker {
+    entries: StdMutex<Vec<Entry>>,
+    /// Wakes the thread when an entry is added
+    added: Condvar,
+}
+
+/// Stops its callback from being called again once dropped
+pub(crate) struct Ticket {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Ticket {
+    /// Stops the callback, like dropping the ticket
+    pub(crate) fn abort(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+}
+
+impl Drop for Ticket {
+    fn drop(&mut self) {
+        self.abort();
+    }
+}
+
+/// Calls `tick` every `interval` from the shared ticker thread
+pub(crate) fn register(interval: Duration, tick: impl FnMut() -> bool + Send + 'static) -> Ticket {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let ticker = ticker();
+    lock_entries(ticker).push(Entry {
+        // A zero interval would keep the thread spinning
+        interval: interval.max(Duration::from_millis(1)),
+        due: Instant::now() + interval,
+        cancelled: cancelled.clone(),
+        tick: Box::new(tick),
+    });
+    ticker.added.notify_one();
+    Ticket { cancelled }
+}
+
+/// The ticker, its thread started on first use
+fn ticker() -> &'static Ticker {
+    static TICKER: OnceLock<Ticker> = OnceLock::new();
+    static STARTED: OnceLock<()> = OnceLock::new();
+
+    let ticker = TICKER.get_or_init(|| Ticker {
+        entries: StdMutex::new(Vec::new()),
+        added: Condvar::new(),
+    });
+    STARTED.get_or_init(|| {
+        thread::Builder::new()
+            .name("throbberous-ticker".to_string())
+            .spawn(move || run(ticker))
+            .expect("failed to spawn the throbberous ticker thread");
+    });
+    ticker
+}
+
+fn lock_entries(ticker: &Ticker) -> MutexGuard<'_, Vec<Entry>> {
+    ticker
+        .entries
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn run(ticker: &Ticker) {
+    loop {
+        let mut due = {
+            let mut entries = lock_entries(ticker);
+            loop {
+                entries.retain(|entry| !entry.cancelled.load(Ordering::Acquire));
+                let now = Instant::now();
+                match entries.iter().map(|entry| entry.due).min() {
+                    Some(next) if next <= now => break,
+                    Some(next) => {
+                        entries = ticker
+                            .added
+                            .wait_timeout(entries, next - now)
+                            .unwrap_or_else(|poisoned| poisoned.into_inner())
+                            .0;
+                    }
+                    None => {
+                        entries = ticker
+                            .added
+                            .wait(entries)
+                            .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    }
+                }
+            }
+
+            // Taken out so callbacks run without the lock, free for widgets registering
+            let now = Instant::now();
+            let (due, waiting) = entries.drain(..).partition(|entry| entry.due <= now);
+            *entries = waiting;
+            due
+        };
+
+        due.retain_mut(|entry| {
+            if entry.cancelled.load(Ordering::Acquire) || !(entry.tick)() {
+                return false;
+            }
+            // A ticker that fell behind skips the missed ticks rather than catching up
+            entry.due = (entry.due + entry.interval).max(Instant::now());
+            true
+        });
+        lock_entries(ticker).append(&mut due);
+    }
+}
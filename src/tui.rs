@@ -0,0 +1,130 @@
+//! Showing widgets inside a ratatui layout
+//!
+//! A [`TuiFrame`] is a [`Renderer`] that keeps the latest frame instead of
+//! writing it to the terminal. The same `Bar`/`Throbber` objects keep driving the
+//! progress; the TUI draws the frame wherever its layout puts it.
+
+use std::sync::{Arc, Mutex as StdMutex};
+
+use crossterm::style::Color;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color as TuiColor, Modifier, Style},
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use crate::{Renderer, RendererFactory, Segment};
+
+/// The latest frame of the widgets drawing into it, renderable as a ratatui widget
+///
+/// Hand [`TuiFrame::factory`] to `BarConfig::renderer` or
+/// `ThrobberConfig::renderer`, then render `&frame` from the TUI's draw loop.
+/// Frames are laid out for the bar's configured `width`, so set
+/// `fit_to_terminal: false` when the area is narrower than the terminal.
+/// A finished frame stays visible, a cleared one leaves the area empty.
+#[derive(Clone, Debug, Default)]
+pub struct TuiFrame {
+    lines: Arc<StdMutex<Vec<Vec<Segment>>>>,
+}
+
+impl TuiFrame {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Factory for `BarConfig::renderer` / `ThrobberConfig::renderer`
+    pub fn factory(&self) -> RendererFactory {
+        let frame = self.clone();
+        Arc::new(move || Box::new(frame.clone()))
+    }
+
+    /// Number of lines in the latest frame, e.g. for a layout constraint
+    pub fn height(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn store(&self, lines: &[Vec<Segment>]) {
+        *self.lock() = lines.to_vec();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Vec<Segment>>> {
+        self.lines
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Renderer for TuiFrame {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn clear(&mut self) {
+        self.store(&[]);
+    }
+}
+
+impl Widget for &TuiFrame {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines = self.lock();
+        for (row, segments) in lines.iter().take(area.height as usize).enumerate() {
+            let line: Line<'_> = segments
+                .iter()
+                .map(|segment| Span::styled(segment.text.as_str(), tui_style(segment)))
+                .collect();
+            buf.set_line(area.x, area.y + row as u16, &line, area.width);
+        }
+    }
+}
+
+/// The ratatui style a segment is drawn with
+fn tui_style(segment: &Segment) -> Style {
+    let mut style = Style::default();
+    if let Some(color) = segment.color {
+        style = style.fg(tui_color(color));
+    }
+    if let Some(background) = segment.style.background {
+        style = style.bg(tui_color(background));
+    }
+    for (set, modifier) in [
+        (segment.style.bold, Modifier::BOLD),
+        (segment.style.dim, Modifier::DIM),
+        (segment.style.italic, Modifier::ITALIC),
+    ] {
+        if set {
+            style = style.add_modifier(modifier);
+        }
+    }
+    style
+}
+
+/// The ratatui color a crossterm color is drawn as, matching ratatui's own backend
+fn tui_color(color: Color) -> TuiColor {
+    match color {
+        Color::Reset => TuiColor::Reset,
+        Color::Black => TuiColor::Black,
+        Color::DarkRed => TuiColor::Red,
+        Color::DarkGreen => TuiColor::Green,
+        Color::DarkYellow => TuiColor::Yellow,
+        Color::DarkBlue => TuiColor::Blue,
+        Color::DarkMagenta => TuiColor::Magenta,
+        Color::DarkCyan => TuiColor::Cyan,
+        Color::Grey => TuiColor::Gray,
+        Color::DarkGrey => TuiColor::DarkGray,
+        Color::Red => TuiColor::LightRed,
+        Color::Green => TuiColor::LightGreen,
+        Color::Yellow => TuiColor::LightYellow,
+        Color::Blue => TuiColor::LightBlue,
+        Color::Magenta => TuiColor::LightMagenta,
+        Color::Cyan => TuiColor::LightCyan,
+        Color::White => TuiColor::White,
+        Color::Rgb { r, g, b } => TuiColor::Rgb(r, g, b),
+        Color::AnsiValue(value) => TuiColor::Indexed(value),
+    }
+}
@@ -0,0 +1,77 @@
+//! Catching configs that would misrender before a bar is drawn with them
+
+use std::{error::Error, fmt};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{Bar, BarConfig};
+
+/// Why a [`BarConfig`] can't be drawn as intended, see [`BarConfig::validate`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// `width` is 0, leaving no cells to fill
+    ZeroWidth,
+    /// `colors` is `Some` but empty; `None` is how colors are turned off
+    NoColors,
+    /// `spinner` is `Some` but has no frames
+    NoSpinnerFrames,
+    /// `refresh_rate` is 0 frames per second
+    ZeroRefreshRate,
+    /// `filled_char` or `empty_char` isn't one column wide, so the bar
+    /// wouldn't be `width` columns
+    CellWidth(char),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::ZeroWidth => write!(f, "bar width is 0"),
+            ConfigError::NoColors => write!(f, "color list is empty; use `None` for no colors"),
+            ConfigError::NoSpinnerFrames => write!(f, "spinner has no frames"),
+            ConfigError::ZeroRefreshRate => write!(f, "refresh rate is 0"),
+            ConfigError::CellWidth(cell) => {
+                write!(f, "bar cell {:?} isn't one column wide", cell)
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+impl BarConfig {
+    /// Checks the config for values the bar can't draw properly
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.width == 0 {
+            return Err(ConfigError::ZeroWidth);
+        }
+        if self.colors.as_ref().is_some_and(Vec::is_empty) {
+            return Err(ConfigError::NoColors);
+        }
+        if self.spinner.as_ref().is_some_and(Vec::is_empty) {
+            return Err(ConfigError::NoSpinnerFrames);
+        }
+        if self.refresh_rate == 0 {
+            return Err(ConfigError::ZeroRefreshRate);
+        }
+        match [self.filled_char, self.empty_char]
+            .into_iter()
+            .find(|cell| cell.width() != Some(1))
+        {
+            Some(cell) => Err(ConfigError::CellWidth(cell)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Bar {
+    /// Like [`Bar::new`], with the config checked by [`BarConfig::validate`]
+    pub fn try_new(total: u64) -> Result<Self, ConfigError> {
+        Self::try_with_config(total, BarConfig::default())
+    }
+
+    /// Like [`Bar::with_config`], once `config` passes [`BarConfig::validate`]
+    pub fn try_with_config(total: u64, config: BarConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+        Ok(Self::with_config(total, config))
+    }
+}
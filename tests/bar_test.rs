@@ -8,3 +8,505 @@ async fn test_bar() {
     bar.finish_with_message("Done!").await;
 }
 
+#[tokio::test]
+async fn test_bar_concurrent_inc() {
+    let bar = throbberous::Bar::new_plain(1000);
+    let mut handles = Vec::new();
+    for _ in 0..10 {
+        let bar = bar.clone();
+        handles.push(tokio::spawn(async move {
+            for _ in 0..100 {
+                bar.inc(1).await;
+            }
+        }));
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    assert_eq!(bar.position(), 1000);
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_bar_handle_from_threads() {
+    let bar = throbberous::Bar::new_plain(400);
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let handle = bar.handle();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    handle.inc(1);
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(bar.position(), 400);
+    assert_eq!(bar.finish().await.total, 400);
+}
+
+#[tokio::test]
+async fn test_bar_finish_report() {
+    let bar = throbberous::Bar::new_plain(10);
+    bar.inc(4).await;
+    let report = bar.finish_with_message("Copied").await;
+    assert_eq!(report.total, 10);
+    assert_eq!(report.message, "Copied");
+    assert!(report.rate > 0.0);
+}
+
+#[tokio::test]
+async fn test_counting_bar_never_finishes_on_its_own() {
+    let bar = throbberous::Bar::counting("events");
+    bar.inc(1_000_000).await;
+    bar.handle().inc(5);
+    assert_eq!(bar.position(), 1_000_005);
+    let report = bar.finish().await;
+    assert_eq!(report.total, 1_000_005);
+}
+
+#[tokio::test]
+async fn test_bar_truncates_long_message_from_start() {
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .truncation(throbberous::Truncation::Start)
+        .build();
+    let bar = throbberous::Bar::with_config(10, config);
+    bar.set_message(format!("copying {}", "nested/".repeat(100)))
+        .await;
+    bar.inc(5).await;
+    let report = bar.finish().await;
+    assert_eq!(report.total, 10);
+}
+
+#[tokio::test]
+async fn test_bytes_bar() {
+    let bar = throbberous::Bar::new_bytes(3 * 1024 * 1024 * 1024);
+    bar.inc(1_300_000_000).await;
+    assert_eq!(bar.position(), 1_300_000_000);
+
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .units(throbberous::Units::DecimalBytes)
+        .build();
+    let counter = throbberous::Bar::counting_with_config("received", config);
+    counter.inc(42_000).await;
+    bar.finish().await;
+    counter.finish().await;
+}
+
+#[tokio::test]
+async fn test_bar_count_display() {
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .count_display(throbberous::CountDisplay::InsteadOfPercent)
+        .count_label(Some("files".to_string()))
+        .build();
+    let bar = throbberous::Bar::with_config(120, config);
+    bar.inc(37).await;
+    assert_eq!(bar.position(), 37);
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_child_bars_advance_parent() {
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .advance_on_child_finish(true)
+        .build();
+    let archives = throbberous::Bar::with_config(3, config);
+    for n in 0..3 {
+        let files = archives.add_child(4, format!("archive-{n}.tar"));
+        files.inc(2).await;
+        let nested = files.add_child(1, "checksum");
+        nested.finish().await;
+        files.inc(2).await;
+        files.finish().await;
+    }
+    assert_eq!(archives.position(), 3);
+    archives.finish().await;
+}
+
+#[tokio::test]
+async fn test_bar_tracks_join_set() {
+    let mut set = tokio::task::JoinSet::new();
+    for n in 0..8u64 {
+        set.spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(n * 5)).await;
+            n
+        });
+    }
+
+    let bar = throbberous::Bar::new_plain(set.len() as u64);
+    let results = bar.track_join_set(&mut set).await;
+    assert_eq!(results.len(), 8);
+    assert_eq!(bar.position(), 8);
+    let sum: u64 = results.into_iter().map(Result::unwrap).sum();
+    assert_eq!(sum, 28);
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_println_above_bar() {
+    let bar = throbberous::Bar::new_plain(3);
+    for n in 0..3 {
+        bar.inc(1).await;
+        throbberous::println(format!("processed item {n}"));
+    }
+    let answer = throbberous::suspend(|| 42);
+    assert_eq!(answer, 42);
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_bar_wrap_future() {
+    let bar = throbberous::Bar::new_plain(4);
+    let handle = bar.handle();
+    let value = bar
+        .wrap_future(async {
+            handle.inc(2);
+            Ok::<_, String>("done")
+        })
+        .await;
+    assert_eq!(value, Ok("done"));
+    assert_eq!(bar.position(), 4);
+
+    let bar = throbberous::Bar::new_plain(4);
+    let failed: Result<(), String> = bar
+        .wrap_future(async { Err("checksum mismatch".into()) })
+        .await;
+    assert!(failed.is_err());
+    assert_eq!(bar.position(), 0);
+}
+
+#[tokio::test]
+async fn test_indeterminate_bar_times_out() {
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .deadline(Some(throbberous::Deadline {
+            hard: Some(std::time::Duration::from_millis(250)),
+            ..Default::default()
+        }))
+        .build();
+    let bar = throbberous::Bar::indeterminate_with_config("Waiting for lock", config);
+    tokio::time::sleep(tokio::time::Duration::from_millis(400)).await;
+    let report = bar.finish_with_message("Got the lock").await;
+    // The timeout already ended the bar, so the late message isn't drawn
+    assert!(report.elapsed >= std::time::Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_subscribe_sees_progress_and_finish() {
+    let bar = throbberous::Bar::new_plain(10);
+    let mut snapshots = bar.subscribe();
+    bar.set_message("Uploading").await;
+    bar.inc(5).await;
+
+    snapshots
+        .wait_for(|snapshot| snapshot.position == 5)
+        .await
+        .unwrap();
+    assert_eq!(snapshots.borrow().total, Some(10));
+    assert_eq!(snapshots.borrow().message, "Uploading");
+
+    bar.finish().await;
+    let last = snapshots
+        .wait_for(|snapshot| snapshot.finished)
+        .await
+        .unwrap();
+    assert_eq!(last.position, 10);
+}
+
+#[tokio::test]
+async fn test_pinned_bar_without_terminal_draws_inline() {
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .pin_to_bottom(true)
+        .build();
+    let bar = throbberous::Bar::with_config(3, config);
+    for _ in 0..3 {
+        println!("working");
+        bar.inc(1).await;
+    }
+    let report = bar.finish().await;
+    assert_eq!(report.total, 3);
+}
+
+#[tokio::test]
+async fn test_phases_follow_progress() {
+    use throbberous::Phase;
+
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .phases(vec![
+            Phase::new("download", 3.0, None),
+            Phase::new("verify", 1.0, None),
+        ])
+        .build();
+    let bar = throbberous::Bar::with_config(100, config);
+    let mut snapshots = bar.subscribe();
+    assert_eq!(bar.phase().unwrap().name, "download");
+
+    bar.inc(74).await;
+    assert_eq!(bar.phase().unwrap().name, "download");
+    bar.inc(1).await;
+    assert_eq!(bar.phase().unwrap().name, "verify");
+
+    bar.set_message("checksums").await;
+    snapshots
+        .wait_for(|snapshot| snapshot.message == "verify: checksums")
+        .await
+        .unwrap();
+
+    bar.finish().await;
+    assert_eq!(bar.phase().unwrap().name, "verify");
+}
+
+#[tokio::test]
+async fn test_switching_between_indeterminate_and_determinate() {
+    let bar = throbberous::Bar::indeterminate_plain("Waiting for headers");
+    let mut snapshots = bar.subscribe();
+    bar.inc(40).await;
+
+    bar.set_total(100).await;
+    let snapshot = snapshots
+        .wait_for(|snapshot| snapshot.total.is_some())
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(snapshot.total, Some(100));
+    assert_eq!(snapshot.position, 40);
+
+    bar.set_indeterminate("Reconnecting").await;
+    snapshots
+        .wait_for(|snapshot| snapshot.total.is_none() && snapshot.message == "Reconnecting")
+        .await
+        .unwrap();
+
+    bar.set_total(30).await;
+    let last = snapshots
+        .wait_for(|snapshot| snapshot.finished)
+        .await
+        .unwrap();
+    assert_eq!(last.position, 30);
+}
+
+#[tokio::test]
+async fn test_download_bar_summary() {
+    let download = throbberous::DownloadBar::with_config(
+        "archive.tar",
+        2048,
+        throbberous::BarConfig::no_colors(),
+    );
+    download.inc(1024).await;
+    download.inc(1024).await;
+
+    let report = download.finish().await;
+    assert_eq!(report.total, 2048);
+    assert!(
+        report
+            .message
+            .starts_with("archive.tar: fetched 2.0 KiB in "),
+        "{}",
+        report.message
+    );
+}
+
+#[tokio::test]
+async fn test_rate_smoothing_keeps_part_of_earlier_samples() {
+    let counting = |smoothing| {
+        throbberous::Bar::counting_with_config(
+            "events",
+            throbberous::BarConfig::builder()
+                .colors(None)
+                .rate_smoothing(smoothing)
+                .build(),
+        )
+    };
+    let smoothed = counting(0.5);
+    let raw = counting(1.0);
+    smoothed.inc(1000).await;
+    raw.inc(1000).await;
+
+    // One window with progress, then one without
+    tokio::time::sleep(tokio::time::Duration::from_millis(2300)).await;
+    assert!(raw.rate().await < 1.0, "{}", raw.rate().await);
+    let rate = smoothed.rate().await;
+    assert!(rate > 200.0 && rate < 600.0, "{rate}");
+
+    smoothed.finish().await;
+    raw.finish().await;
+}
+
+#[tokio::test]
+async fn test_cloned_bar_shares_progress_and_drawing() {
+    let bar = throbberous::Bar::new_plain(40);
+    let mut tasks = Vec::new();
+    for _ in 0..4 {
+        let bar = bar.clone();
+        tasks.push(tokio::spawn(async move {
+            for _ in 0..10 {
+                bar.inc(1).await;
+            }
+        }));
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    // The clones are gone, the original still draws and finishes
+    assert_eq!(bar.position(), 40);
+    let report = bar.finish().await;
+    assert_eq!(report.total, 40);
+}
+
+#[tokio::test]
+async fn test_config_builders() {
+    let config = throbberous::BarConfig::builder()
+        .width(12)
+        .filled_char('#')
+        .count_label(Some("files".to_string()))
+        .build();
+    assert_eq!(config.width, 12);
+    assert_eq!(config.filled_char, '#');
+    assert_eq!(config.count_label.as_deref(), Some("files"));
+
+    // Starting from an existing config keeps its other fields
+    let config = throbberous::BarConfigBuilder::from(throbberous::BarConfig::no_colors())
+        .smooth(true)
+        .build();
+    assert!(config.colors.is_none());
+    assert!(config.smooth);
+
+    let config = throbberous::ThrobberConfig::builder()
+        .frame_delay(40)
+        .show_elapsed(true)
+        .build();
+    assert_eq!(config.frame_delay, 40);
+    assert!(config.show_elapsed);
+}
+
+#[tokio::test]
+async fn test_paused_time_is_not_counted() {
+    let bar = throbberous::Bar::new_plain(10);
+    bar.inc(5).await;
+    bar.pause().await;
+    assert!(bar.is_paused().await);
+    tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+    assert!(bar.snapshot().await.elapsed < std::time::Duration::from_millis(100));
+
+    bar.resume().await;
+    assert!(!bar.is_paused().await);
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    let report = bar.finish().await;
+    assert!(report.elapsed >= std::time::Duration::from_millis(50));
+    assert!(report.elapsed < std::time::Duration::from_millis(250));
+}
+
+#[tokio::test]
+async fn test_zero_total_bar_finishes_or_waits_for_a_total() {
+    let bar = throbberous::Bar::new_plain(0);
+    let mut snapshots = bar.subscribe();
+    let last = snapshots
+        .wait_for(|snapshot| snapshot.finished)
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(last.total, Some(0));
+
+    let config = throbberous::BarConfig::builder()
+        .colors(None)
+        .zero_total(throbberous::ZeroTotal::Indeterminate)
+        .build();
+    let bar = throbberous::Bar::with_config(0, config);
+    let mut snapshots = bar.subscribe();
+    bar.set_message("Listing files").await;
+    let snapshot = snapshots
+        .wait_for(|snapshot| snapshot.message == "Listing files")
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(snapshot.total, None);
+    assert!(!snapshot.finished);
+
+    bar.set_total(3).await;
+    bar.inc(3).await;
+    snapshots
+        .wait_for(|snapshot| snapshot.finished)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_counts_saturate_near_u64_max() {
+    use throbberous::{BarConfig, OutputMode};
+
+    let config = BarConfig::builder().output(OutputMode::Hidden).build();
+    let bar = throbberous::Bar::with_config(u64::MAX, config);
+    let mut snapshots = bar.subscribe();
+    bar.inc_ok(u64::MAX - 1).await;
+    bar.inc_failed(5).await;
+    assert_eq!(bar.position(), u64::MAX);
+    assert_eq!(bar.succeeded(), u64::MAX - 1);
+    assert_eq!(bar.failed(), 5);
+
+    // Overshooting the total finishes the bar rather than wrapping around
+    let last = snapshots
+        .wait_for(|snapshot| snapshot.finished)
+        .await
+        .unwrap();
+    assert_eq!(last.position, u64::MAX);
+    assert_eq!(bar.finish().await.total, u64::MAX);
+}
+
+#[test]
+fn test_try_new_rejects_configs_that_would_misrender() {
+    use throbberous::{BarConfig, ConfigError};
+
+    let config = BarConfig::builder().width(0).build();
+    assert_eq!(config.validate(), Err(ConfigError::ZeroWidth));
+
+    let config = BarConfig::builder().colors(Some(Vec::new())).build();
+    assert_eq!(config.validate(), Err(ConfigError::NoColors));
+
+    let config = BarConfig::builder().filled_char('🟩').build();
+    assert_eq!(config.validate(), Err(ConfigError::CellWidth('🟩')));
+
+    assert_eq!(BarConfig::default().validate(), Ok(()));
+    assert_eq!(BarConfig::rocket().validate(), Ok(()));
+}
+
+#[tokio::test]
+async fn test_try_new_creates_a_valid_bar() {
+    let bar = throbberous::Bar::try_new(2).unwrap();
+    bar.inc(2).await;
+    bar.finish().await;
+    assert!(throbberous::Bar::try_with_config(
+        2,
+        throbberous::BarConfig::builder().refresh_rate(0).build()
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_from_snapshot_caps_position_at_total() {
+    use throbberous::{BarConfig, ConfigError, ProgressSnapshot};
+
+    let snapshot = ProgressSnapshot {
+        position: 250,
+        total: Some(200),
+        message: "Indexing".to_string(),
+        ..ProgressSnapshot::default()
+    };
+    let bar = throbberous::Bar::from_snapshot(snapshot.clone(), BarConfig::no_colors()).unwrap();
+    assert_eq!(bar.snapshot().await.position, 200);
+    bar.finish().await;
+
+    let config = BarConfig::builder().colors(None).width(0).build();
+    assert_eq!(
+        throbberous::Bar::from_snapshot(snapshot, config).err(),
+        Some(ConfigError::ZeroWidth)
+    );
+}
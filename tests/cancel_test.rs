@@ -0,0 +1,70 @@
+#![cfg(feature = "cancellation")]
+
+use std::sync::{Arc, Mutex};
+
+use throbberous::{
+    Bar, BarConfig, CancelAction, CancellationToken, Renderer, Segment, Throbber, ThrobberConfig,
+};
+
+/// Records which renderer calls a widget made
+struct Calls(Arc<Mutex<Vec<&'static str>>>);
+
+impl Renderer for Calls {
+    fn draw(&mut self, _lines: &[Vec<Segment>]) {
+        self.0.lock().unwrap().push("draw");
+    }
+
+    fn finish(&mut self, _lines: &[Vec<Segment>]) {
+        self.0.lock().unwrap().push("finish");
+    }
+
+    fn clear(&mut self) {
+        self.0.lock().unwrap().push("clear");
+    }
+}
+
+#[tokio::test]
+async fn test_cancelled_bar_stops() {
+    let token = CancellationToken::new();
+    let config = BarConfig::builder()
+        .colors(None)
+        .cancel_token(Some(token.clone()))
+        .build();
+    let bar = Bar::with_config(100, config);
+    bar.inc(40).await;
+
+    token.cancel();
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    // Updates after cancellation are ignored and finishing keeps the position
+    bar.inc(10).await;
+    let report = bar.finish().await;
+    assert_eq!(report.total, 40);
+}
+
+#[tokio::test]
+async fn test_cancelled_throbber_clears() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let shared = calls.clone();
+    let token = CancellationToken::new();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .renderer(Some(Arc::new(move || Box::new(Calls(shared.clone())))))
+        .cancel_token(Some(token.clone()))
+        .on_cancel(CancelAction::Clear)
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    token.cancel();
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    // The line is cleared rather than finished
+    assert_eq!(calls.lock().unwrap().last(), Some(&"clear"));
+    assert!(!calls.lock().unwrap().contains(&"finish"));
+
+    // Stopping an already cancelled throbber is a no-op
+    throbber.stop().await;
+    assert_eq!(calls.lock().unwrap().last(), Some(&"clear"));
+}
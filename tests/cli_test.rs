@@ -0,0 +1,42 @@
+#![cfg(feature = "clap")]
+
+use clap::Parser;
+use throbberous::{BarConfig, ColorChoice, OutputMode, ProgressArgs, ProgressStyle};
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    progress: ProgressArgs,
+}
+
+fn parse(args: &[&str]) -> ProgressArgs {
+    Cli::parse_from(std::iter::once("mytool").chain(args.iter().copied())).progress
+}
+
+#[test]
+fn test_progress_flags_pick_a_style() {
+    assert_eq!(parse(&[]).style(), ProgressStyle::Colored);
+    assert_eq!(parse(&["--plain"]).style(), ProgressStyle::Plain);
+    assert_eq!(parse(&["-q"]).style(), ProgressStyle::Hidden);
+    assert_eq!(parse(&["--quiet"]).style(), ProgressStyle::Hidden);
+    assert_eq!(
+        parse(&["--no-progress", "--plain"]).style(),
+        ProgressStyle::Hidden
+    );
+}
+
+#[test]
+fn test_progress_flags_adjust_configs() {
+    let hidden = parse(&["--no-progress"]).bar_config();
+    assert_eq!(hidden.output, OutputMode::Hidden);
+
+    let plain = parse(&["--plain"]).throbber_config();
+    assert!(plain.colors.is_none());
+    assert_eq!(plain.color_choice, ColorChoice::Never);
+
+    // Settings the flags don't cover are kept
+    let config = BarConfig::builder().width(60).build();
+    let colored = parse(&[]).apply_to_bar(config);
+    assert_eq!(colored.width, 60);
+    assert!(colored.colors.is_some());
+}
@@ -0,0 +1,62 @@
+use crossterm::style::Color;
+use throbberous::ColorDepth;
+
+#[test]
+fn test_true_color_keeps_rgb() {
+    let orange = Color::Rgb {
+        r: 255,
+        g: 135,
+        b: 0,
+    };
+    assert_eq!(ColorDepth::TrueColor.downsample(orange), orange);
+}
+
+#[test]
+fn test_rgb_downsampled_to_256_colors() {
+    let orange = Color::Rgb {
+        r: 255,
+        g: 135,
+        b: 0,
+    };
+    assert_eq!(
+        ColorDepth::Ansi256.downsample(orange),
+        Color::AnsiValue(208)
+    );
+
+    // Grays go to the grayscale ramp rather than the cube
+    let gray = Color::Rgb {
+        r: 120,
+        g: 120,
+        b: 120,
+    };
+    assert_eq!(ColorDepth::Ansi256.downsample(gray), Color::AnsiValue(243));
+
+    assert_eq!(
+        ColorDepth::Ansi256.downsample(Color::AnsiValue(99)),
+        Color::AnsiValue(99)
+    );
+}
+
+#[test]
+fn test_rgb_and_256_colors_downsampled_to_16() {
+    let pink = Color::Rgb {
+        r: 250,
+        g: 20,
+        b: 230,
+    };
+    assert_eq!(ColorDepth::Ansi16.downsample(pink), Color::Magenta);
+    assert_eq!(
+        ColorDepth::Ansi16.downsample(Color::AnsiValue(9)),
+        Color::Red
+    );
+    assert_eq!(
+        ColorDepth::Ansi16.downsample(Color::AnsiValue(22)),
+        Color::DarkGreen
+    );
+
+    // Named colors are left alone
+    assert_eq!(
+        ColorDepth::Ansi16.downsample(Color::DarkCyan),
+        Color::DarkCyan
+    );
+}
@@ -0,0 +1,21 @@
+// The environment is read once per process, hence a test binary of its own
+
+use throbberous::{Bar, BarConfig, OutputMode};
+
+#[tokio::test]
+async fn test_disabled_env_hides_every_widget() {
+    std::env::set_var("THROBBEROUS_DISABLED", "1");
+
+    assert_eq!(OutputMode::Terminal.resolve(), OutputMode::Hidden);
+    assert_eq!(OutputMode::Auto.resolve(), OutputMode::Hidden);
+
+    // Hidden bars still count and finish as usual
+    let config = BarConfig::builder()
+        .colors(None)
+        .output(OutputMode::Terminal)
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.inc(4).await;
+    let report = bar.finish().await;
+    assert_eq!(report.total, 10);
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use throbberous::format;
+
+#[test]
+fn test_duration_format() {
+    assert_eq!(format::duration(Duration::from_millis(850)), "850ms");
+    assert_eq!(format::duration(Duration::from_secs(42)), "42s");
+    assert_eq!(format::duration(Duration::from_secs(133)), "2m 13s");
+    assert_eq!(format::duration(Duration::from_secs(3_840)), "1h 04m");
+    assert_eq!(
+        format::duration(Duration::from_secs(3 * 86_400 + 7 * 3_600)),
+        "3d 07h"
+    );
+}
+
+#[test]
+fn test_count_and_bytes_format() {
+    assert_eq!(format::count(48_221), "48,221");
+    assert_eq!(format::bytes(512.0, throbberous::Units::Bytes), "512 B");
+    assert_eq!(
+        format::bytes(13_002_342.0, throbberous::Units::Bytes),
+        "12.4 MiB"
+    );
+    assert_eq!(
+        format::bytes(1_300_000_000.0, throbberous::Units::DecimalBytes),
+        "1.3 GB"
+    );
+}
+
+#[test]
+fn test_bytes_precision() {
+    let units = throbberous::Units::Bytes;
+    assert_eq!(
+        format::bytes_with_precision(13_002_342.0, units, 2),
+        "12.40 MiB"
+    );
+    assert_eq!(
+        format::bytes_with_precision(13_002_342.0, units, 0),
+        "12 MiB"
+    );
+    assert_eq!(format::bytes_with_precision(512.0, units, 3), "512 B");
+}
@@ -0,0 +1,59 @@
+use throbberous::io::{copy_file_with_progress, copy_with_progress};
+
+#[tokio::test]
+async fn test_copy_with_progress_advances_bar() {
+    let data = vec![7u8; 200_000];
+    let bar = throbberous::Bar::new_plain(data.len() as u64);
+    let mut reader = data.as_slice();
+    let mut writer = Vec::new();
+
+    let copied = copy_with_progress(&mut reader, &mut writer, &bar)
+        .await
+        .unwrap();
+    assert_eq!(copied, 200_000);
+    assert_eq!(writer, data);
+    assert_eq!(bar.position(), 200_000);
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_copy_file_with_progress() {
+    let dir = std::env::temp_dir().join(format!("throbberous-io-{}", std::process::id()));
+    tokio::fs::create_dir_all(&dir).await.unwrap();
+    let src = dir.join("source.bin");
+    let dst = dir.join("copy.bin");
+    tokio::fs::write(&src, vec![1u8; 100_000]).await.unwrap();
+
+    let copied = copy_file_with_progress(&src, &dst).await.unwrap();
+    assert_eq!(copied, 100_000);
+    assert_eq!(tokio::fs::read(&dst).await.unwrap().len(), 100_000);
+
+    let missing = copy_file_with_progress(dir.join("missing.bin"), &dst).await;
+    assert!(missing.is_err());
+
+    tokio::fs::remove_dir_all(&dir).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_progress_lines_counts_lines() {
+    use throbberous::io::{count_lines, ProgressLines};
+
+    let path = std::env::temp_dir().join(format!("throbberous-lines-{}.csv", std::process::id()));
+    tokio::fs::write(&path, "id,name\r\n1,a\n2,b")
+        .await
+        .unwrap();
+    assert_eq!(count_lines(&path).await.unwrap(), 3);
+
+    let bar = throbberous::Bar::new_plain(3);
+    let file = tokio::io::BufReader::new(tokio::fs::File::open(&path).await.unwrap());
+    let mut lines = ProgressLines::new(file, &bar);
+    let mut read = Vec::new();
+    while let Some(line) = lines.next_line().await.unwrap() {
+        read.push(line);
+    }
+
+    assert_eq!(read, ["id,name", "1,a", "2,b"]);
+    assert_eq!(bar.position(), 3);
+    bar.finish().await;
+    tokio::fs::remove_file(&path).await.unwrap();
+}
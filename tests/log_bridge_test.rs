@@ -0,0 +1,35 @@
+#![cfg(feature = "log-bridge")]
+
+use std::sync::{Arc, Mutex};
+
+/// Minimal logger that keeps warnings and above
+struct Collect(Arc<Mutex<Vec<String>>>);
+
+impl log::Log for Collect {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+#[tokio::test]
+async fn test_log_bridge_prints_above_bar() {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    throbberous::LogBridge::new(Collect(records.clone()))
+        .install(log::LevelFilter::Trace)
+        .unwrap();
+
+    let bar = throbberous::Bar::new_plain(10);
+    bar.inc(5).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    log::info!("filtered by the inner logger");
+    log::warn!("disk almost full");
+    bar.finish().await;
+
+    assert_eq!(*records.lock().unwrap(), ["disk almost full"]);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "mirror")]
+
+use std::time::Duration;
+
+use throbberous::{mirror, Bar, BarConfig, OutputMode, ProgressSnapshot};
+use tokio::io::BufReader;
+
+fn hidden() -> BarConfig {
+    BarConfig::builder().output(OutputMode::Hidden).build()
+}
+
+#[tokio::test]
+async fn test_mirrored_bar_follows_the_sender() {
+    let (worker_end, parent_end) = tokio::io::duplex(4096);
+    let worker = Bar::with_config(10, hidden());
+    let sending = tokio::spawn(mirror::send_progress(worker.subscribe(), worker_end));
+
+    let parent = Bar::with_config(1, hidden());
+    let mut snapshots = parent.subscribe();
+    let mirroring = tokio::spawn(async move {
+        let report = mirror::mirror_progress(BufReader::new(parent_end), &parent).await;
+        (report, parent)
+    });
+
+    worker.set_message("unpacking").await;
+    worker.set_position(4).await;
+    let snapshot = snapshots
+        .wait_for(|snapshot| snapshot.position == 4)
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(snapshot.total, Some(10));
+    assert_eq!(snapshot.message, "unpacking");
+
+    worker.set_position(7).await;
+    worker
+        .finish_with_status(throbberous::Status::Error, "disk full")
+        .await;
+    sending.await.unwrap().unwrap();
+
+    let (report, parent) = mirroring.await.unwrap();
+    let report = report.unwrap().expect("the sender finished");
+    assert_eq!(report.message, "disk full");
+    // Finished where the worker stopped rather than filled
+    assert_eq!(parent.position(), 7);
+}
+
+#[tokio::test]
+async fn test_mirror_turns_bar_indeterminate() {
+    let snapshot = ProgressSnapshot {
+        position: 12,
+        total: None,
+        message: "scanning".to_string(),
+        detail: Some("src/lib.rs".to_string()),
+        finished: false,
+        elapsed: Duration::ZERO,
+    };
+    let line = serde_json::to_string(&snapshot).unwrap() + "\n";
+
+    let bar = Bar::with_config(100, hidden());
+    let report = mirror::mirror_progress(line.as_bytes(), &bar)
+        .await
+        .unwrap();
+    assert!(report.is_none());
+
+    let mirrored = bar.snapshot().await;
+    assert_eq!(mirrored.total, None);
+    assert_eq!(mirrored.position, 12);
+    assert_eq!(mirrored.message, "scanning");
+    assert_eq!(mirrored.detail.as_deref(), Some("src/lib.rs"));
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_mirror_rejects_garbage() {
+    let bar = Bar::with_config(10, hidden());
+    let error = mirror::mirror_progress(&b"not a snapshot\n"[..], &bar)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    bar.finish().await;
+}
@@ -0,0 +1,149 @@
+use std::sync::{Arc, Mutex};
+
+use throbberous::{BarConfig, MultiBar, Renderer, Segment};
+
+/// Keeps the last frame drawn or finished as plain text
+struct LastFrame(Arc<Mutex<String>>);
+
+impl LastFrame {
+    fn store(&mut self, lines: &[Vec<Segment>]) {
+        *self.0.lock().unwrap() = lines
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_str()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+}
+
+impl Renderer for LastFrame {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn clear(&mut self) {
+        self.store(&[]);
+    }
+}
+
+#[tokio::test]
+async fn test_multi_bar_insert_and_remove() {
+    let multi = throbberous::MultiBar::new_plain();
+    let first = multi.add(10, "first");
+    let last = multi.add(10, "last");
+    let middle = multi.insert_after(&first, 10, "middle");
+    let top = multi.insert_before(&first, 10, "top");
+    assert_eq!(multi.len(), 4);
+
+    middle.inc(10).await;
+    assert!(multi.remove(&middle));
+    assert!(!multi.remove(&middle));
+    assert_eq!(multi.len(), 3);
+
+    first.add_child(3, "nested").inc(1).await;
+    assert_eq!(multi.len(), 4);
+    assert!(multi.remove(&first));
+    assert_eq!(multi.len(), 2);
+
+    top.finish().await;
+    last.finish().await;
+    multi.finish().await;
+}
+
+#[tokio::test]
+async fn test_multi_bar_removes_finished() {
+    let multi = throbberous::MultiBar::new_plain();
+    multi.set_remove_finished(true);
+
+    let jobs: Vec<_> = (0..5).map(|n| multi.add(4, format!("job {n}"))).collect();
+    for job in &jobs {
+        job.inc(4).await;
+    }
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert!(multi.is_empty());
+    multi.finish().await;
+}
+
+#[tokio::test]
+async fn test_multi_bar_total_line_sums_bars() {
+    let frame = Arc::new(Mutex::new(String::new()));
+    let shared = frame.clone();
+    let multi = MultiBar::with_config(
+        BarConfig::builder()
+            .colors(None)
+            .renderer(Some(Arc::new(move || Box::new(LastFrame(shared.clone())))))
+            .fit_to_terminal(false)
+            .width(10)
+            .build(),
+    );
+    multi.set_remove_finished(true);
+    multi.show_total("total");
+
+    let small = multi.add(4, "small");
+    let large = multi.add(6, "large");
+    small.inc(4).await;
+    large.inc(1).await;
+    large.add_child(100, "nested").inc(50).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    // The finished bar is gone but still counts; the nested bar doesn't
+    assert_eq!(
+        *frame.lock().unwrap(),
+        "[==        ] 17% large\n  [=====     ] 50% nested\n[=====     ] 50% total"
+    );
+
+    large.finish().await;
+    multi.finish().await;
+    assert_eq!(*frame.lock().unwrap(), "[==========] 100% total");
+}
+
+#[tokio::test]
+async fn test_multi_bar_total_line_skips_indeterminate_bars() {
+    let frame = Arc::new(Mutex::new(String::new()));
+    let shared = frame.clone();
+    let multi = MultiBar::with_config(
+        BarConfig::builder()
+            .colors(None)
+            .renderer(Some(Arc::new(move || Box::new(LastFrame(shared.clone())))))
+            .fit_to_terminal(false)
+            .width(10)
+            .build(),
+    );
+    multi.set_remove_finished(true);
+    multi.show_total("total");
+
+    let download = multi.add(4, "download");
+    let waiting = multi.add(4, "waiting");
+    waiting.set_indeterminate("waiting").await;
+    let done = multi.add(u64::MAX, "done");
+    done.set_indeterminate("done").await;
+    done.finish().await;
+    download.inc(2).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    // Only the determinate bar adds up, the retired indeterminate one doesn't overflow it
+    let last = frame.lock().unwrap().lines().last().unwrap().to_string();
+    assert_eq!(last, "[=====     ] 50% total");
+
+    download.finish().await;
+    waiting.finish().await;
+    multi.finish().await;
+}
+
+#[tokio::test]
+async fn test_dashboard_without_terminal_draws_inline() {
+    let config = BarConfig::builder()
+        .colors(None)
+        .alternate_screen(true)
+        .build();
+    let multi = MultiBar::with_config(config);
+    let bars: Vec<_> = (0..3)
+        .map(|job| multi.add(2, format!("job {job}")))
+        .collect();
+    for bar in &bars {
+        bar.inc(2).await;
+    }
+    multi.finish().await;
+    assert!(bars.iter().all(|bar| bar.position() == 2));
+}
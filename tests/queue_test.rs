@@ -0,0 +1,87 @@
+use std::sync::{Arc, Mutex};
+
+use throbberous::{Renderer, Segment, Status, StatusTheme, TaskQueue, ThrobberConfig};
+
+/// Records every frame drawn or finished as plain text
+struct Frames(Arc<Mutex<Vec<String>>>);
+
+impl Frames {
+    fn store(&mut self, lines: &[Vec<Segment>]) {
+        self.0.lock().unwrap().push(
+            lines
+                .iter()
+                .map(|line| line.iter().map(|s| s.text.as_str()).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+}
+
+impl Renderer for Frames {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn clear(&mut self) {}
+}
+
+fn queue() -> (Arc<Mutex<Vec<String>>>, TaskQueue) {
+    let frames = Arc::new(Mutex::new(Vec::new()));
+    let shared = frames.clone();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .frames(vec!["*".to_string()])
+        .status_theme(StatusTheme::ascii())
+        .fit_to_terminal(false)
+        .renderer(Some(Arc::new(move || Box::new(Frames(shared.clone())))))
+        .build();
+    (frames, TaskQueue::with_config(config))
+}
+
+async fn step(result: Result<(), &'static str>) -> Result<(), &'static str> {
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    result
+}
+
+#[tokio::test]
+async fn test_queue_runs_every_task_and_summarizes() {
+    let (frames, mut queue) = queue();
+    queue
+        .push("Building", step(Ok(())))
+        .push("Migrating database", step(Err("connection refused")))
+        .push("Restarting", step(Ok(())));
+    let report = queue.run().await;
+
+    assert_eq!(report.count(Status::Success), 2);
+    assert_eq!(report.tasks[1].status, Status::Error);
+    assert_eq!(report.tasks[1].error.as_deref(), Some("connection refused"));
+    assert!(!report.is_success());
+
+    let frames = frames.lock().unwrap();
+    assert!(frames.contains(&"* 2/3: Migrating database".to_string()));
+    assert!(frames.contains(&"* 3/3: Restarting".to_string()));
+    assert_eq!(
+        frames.last().unwrap(),
+        "FAIL 2/3 tasks succeeded, 1 failed: Migrating database"
+    );
+}
+
+#[tokio::test]
+async fn test_queue_fail_fast_skips_the_rest() {
+    let (frames, mut queue) = queue();
+    queue
+        .fail_fast(true)
+        .push("Building", step(Err("compile error")))
+        .push("Deploying", step(Ok(())));
+    let report = queue.run().await;
+
+    assert_eq!(report.tasks[1].status, Status::Cancelled);
+    assert_eq!(
+        frames.lock().unwrap().last().unwrap(),
+        "FAIL 0/2 tasks succeeded, 1 failed: Building, 1 skipped"
+    );
+}
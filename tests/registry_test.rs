@@ -0,0 +1,60 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use throbberous::{BarConfig, OutputMode};
+
+/// The active count is process-wide, tests reading it take turns
+static SERIAL: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+#[tokio::test]
+async fn test_conflict_hook_fires_for_overlapping_widgets() {
+    let _serial = SERIAL.lock().await;
+    let conflicts = Arc::new(AtomicUsize::new(0));
+    let seen = conflicts.clone();
+    throbberous::set_conflict_hook(move |_conflict| {
+        seen.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let first = throbberous::Bar::new_plain(10);
+    let second = throbberous::Bar::new_plain(10);
+    tokio::task::yield_now().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+
+    first.finish().await;
+    second.finish().await;
+
+    assert!(conflicts.load(Ordering::SeqCst) >= 1);
+}
+
+#[tokio::test]
+async fn test_hidden_and_json_widgets_are_not_counted() {
+    let _serial = SERIAL.lock().await;
+    let conflicts = Arc::new(Mutex::new(Vec::new()));
+    let seen = conflicts.clone();
+    throbberous::set_conflict_hook(move |conflict| {
+        seen.lock().unwrap().push(conflict.widget);
+    });
+
+    let hidden = throbberous::Bar::with_config(
+        10,
+        BarConfig::builder()
+            .colors(None)
+            .output(OutputMode::Hidden)
+            .build(),
+    );
+    let json = throbberous::Bar::with_config(
+        10,
+        BarConfig::builder()
+            .colors(None)
+            .output(OutputMode::Json)
+            .build(),
+    );
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    assert_eq!(throbberous::active_widgets(), 0);
+
+    hidden.finish().await;
+    json.finish().await;
+    assert!(conflicts.lock().unwrap().is_empty());
+}
@@ -0,0 +1,1284 @@
+use std::sync::{Arc, Mutex};
+
+use throbberous::{
+    Bar, BarConfig, BarConfigBuilder, Renderer, Segment, Throbber, ThrobberConfig,
+    ThrobberConfigBuilder,
+};
+
+/// Records every call as plain text instead of drawing
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Recorder {
+    fn text(lines: &[Vec<Segment>]) -> String {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_str()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Renderer for Recorder {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("draw {}", Self::text(lines)));
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("finish {}", Self::text(lines)));
+    }
+
+    fn clear(&mut self) {
+        self.0.lock().unwrap().push("clear".to_string());
+    }
+}
+
+fn recorder() -> (Arc<Mutex<Vec<String>>>, throbberous::RendererFactory) {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let shared = log.clone();
+    (log, Arc::new(move || Box::new(Recorder(shared.clone()))))
+}
+
+#[tokio::test]
+async fn test_bar_draws_through_renderer() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .build();
+    let bar = Bar::with_config(4, config);
+    bar.inc(2).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(log
+        .iter()
+        .any(|call| call.starts_with("draw [=====     ] 50%")));
+    assert!(log.last().unwrap().starts_with("finish [==========] 100%"));
+}
+
+#[tokio::test]
+async fn test_clones_finishing_together_both_wait_for_the_final_frame() {
+    /// Takes its time writing the final frame
+    struct SlowFinish(Arc<Mutex<Vec<String>>>);
+
+    impl Renderer for SlowFinish {
+        fn draw(&mut self, _lines: &[Vec<Segment>]) {}
+        fn finish(&mut self, lines: &[Vec<Segment>]) {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            self.0.lock().unwrap().push(Recorder::text(lines));
+        }
+        fn clear(&mut self) {}
+    }
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let shared = log.clone();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(Arc::new(move || Box::new(SlowFinish(shared.clone())))))
+        .fit_to_terminal(false)
+        .width(10)
+        .build();
+    let bar = Bar::with_config(4, config);
+
+    let finishers: Vec<_> = [bar.clone(), bar]
+        .into_iter()
+        .map(|bar| {
+            let log = log.clone();
+            tokio::spawn(async move {
+                let report = bar.finish().await;
+                // The final frame is out before either clone returns
+                (report.total, log.lock().unwrap().len())
+            })
+        })
+        .collect();
+    for finisher in finishers {
+        assert_eq!(finisher.await.unwrap(), (4, 1));
+    }
+    assert!(log.lock().unwrap()[0].starts_with("[==========] 100%"));
+}
+
+#[tokio::test]
+async fn test_throbber_draws_through_renderer() {
+    let (log, factory) = recorder();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .frame_delay(20)
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("Working").await;
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    throbber.stop().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    throbber.stop_success("Done").await;
+
+    let log = log.lock().unwrap();
+    assert!(log.iter().any(|call| call.ends_with(" Working")));
+    assert!(log.iter().any(|call| call == "clear"));
+    assert!(log.last().unwrap().starts_with("finish ") && log.last().unwrap().ends_with(" Done"));
+}
+
+#[test]
+fn test_explicit_output_modes_resolve_to_themselves() {
+    use throbberous::OutputMode;
+
+    assert_eq!(OutputMode::Terminal.resolve(), OutputMode::Terminal);
+    assert_eq!(OutputMode::Json.resolve(), OutputMode::Json);
+    assert_eq!(OutputMode::Append.resolve(), OutputMode::Append);
+    assert_ne!(OutputMode::Auto.resolve(), OutputMode::Auto);
+}
+
+#[test]
+fn test_widgets_draw_on_stderr_by_default() {
+    use throbberous::{DrawTarget, OutputMode};
+
+    assert_eq!(BarConfig::default().target, DrawTarget::Stderr);
+    assert_eq!(ThrobberConfig::default().target, DrawTarget::Stderr);
+    assert_eq!(
+        OutputMode::Auto.resolve(),
+        OutputMode::Auto.resolve_for(DrawTarget::Stderr)
+    );
+    // A stream piped elsewhere gets plain lines instead of animation
+    for target in [DrawTarget::Stdout, DrawTarget::Stderr] {
+        if !target.is_terminal() {
+            assert_eq!(OutputMode::Auto.resolve_for(target), OutputMode::Append);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_custom_renderer_receives_line_progress() {
+    struct Data(Arc<Mutex<Vec<throbberous::LineProgress>>>);
+
+    impl Renderer for Data {
+        fn draw(&mut self, _lines: &[Vec<Segment>]) {}
+        fn finish(&mut self, _lines: &[Vec<Segment>]) {}
+        fn clear(&mut self) {}
+        fn progress(&mut self, lines: &[throbberous::LineProgress]) {
+            self.0.lock().unwrap().extend_from_slice(lines);
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let shared = seen.clone();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(Arc::new(move || Box::new(Data(shared.clone())))))
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.set_message("Copying").await;
+    bar.inc(10).await;
+    bar.finish().await;
+
+    let seen = seen.lock().unwrap();
+    let last = seen.last().unwrap();
+    assert_eq!(last.position, Some(10));
+    assert_eq!(last.length, Some(10));
+    assert_eq!(last.message, "Copying");
+}
+
+#[tokio::test]
+async fn test_append_mode_bar_finishes() {
+    let config = BarConfig::builder()
+        .colors(None)
+        .output(throbberous::OutputMode::Append)
+        .build();
+    let bar = Bar::with_config(100, config);
+    for _ in 0..100 {
+        bar.inc(1).await;
+    }
+    let report = bar.finish().await;
+    assert_eq!(report.total, 100);
+}
+
+#[tokio::test]
+async fn test_accessible_mode_widgets_finish() {
+    let config = BarConfig::builder()
+        .colors(None)
+        .output(throbberous::OutputMode::Accessible)
+        .build();
+    let bar = Bar::with_config(100, config);
+    for _ in 0..100 {
+        bar.inc(1).await;
+    }
+    assert_eq!(bar.finish().await.total, 100);
+
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .output(throbberous::OutputMode::Accessible)
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.start().await;
+    throbber.stop_success("Built").await;
+}
+
+#[tokio::test]
+#[ignore = "run by test_accessible_mode_announces_in_strings"]
+async fn child_accessible_announcements() {
+    let config = BarConfig::builder()
+        .colors(None)
+        .output(throbberous::OutputMode::Accessible)
+        .target(throbberous::DrawTarget::Stdout)
+        .strings(throbberous::Strings {
+            finished: "terminé".to_string(),
+            percent_complete: "{} pour cent".to_string(),
+            ..throbberous::Strings::default()
+        })
+        .build();
+    let bar = Bar::with_config(4, config);
+    bar.set_message("Compilation").await;
+    bar.set_position(2).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    bar.finish().await;
+}
+
+#[test]
+fn test_accessible_mode_announces_in_strings() {
+    // Announcements go straight to stdout, which only a child process can capture
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "child_accessible_announcements",
+            "--exact",
+            "--include-ignored",
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("Compilation: 50 pour cent\n"), "{}", stdout);
+    assert!(stdout.contains("Compilation: terminé\n"), "{}", stdout);
+    assert!(!stdout.contains("percent"), "{}", stdout);
+}
+
+#[tokio::test]
+async fn test_bar_draws_with_configured_characters() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .filled_char('#')
+        .empty_char('-')
+        .head_char(Some('>'))
+        .left_bracket("|".to_string())
+        .right_bracket("|".to_string())
+        .build();
+    let bar = Bar::with_config(16, config);
+    bar.set_position(5).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    // The head takes the last filled cell until the bar is full
+    assert!(
+        log.iter()
+            .any(|call| call.starts_with("draw |##>-------| 31%")),
+        "{:?}",
+        log
+    );
+    assert!(log.last().unwrap().starts_with("finish |##########| 100%"));
+}
+
+#[tokio::test]
+async fn test_smooth_bar_ends_in_an_eighth_block() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .smooth(true)
+        .charset(throbberous::Charset::Unicode)
+        .build();
+    let bar = Bar::with_config(16, config);
+    // 3/16 of 10 cells is one full block and seven eighths of the next
+    bar.set_position(3).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    bar.set_position(5).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(
+        log.iter().any(|call| call.starts_with("draw [█▉        ]")),
+        "{:?}",
+        log
+    );
+    assert!(
+        log.iter().any(|call| call.starts_with("draw [███▏      ]")),
+        "{:?}",
+        log
+    );
+    assert!(log.last().unwrap().starts_with("finish [██████████]"));
+}
+
+/// Waits until `bar` has drawn more than `frames` frames
+async fn drawn_after(bar: &Bar, frames: u64) {
+    for _ in 0..200 {
+        if bar.render_stats().frames_drawn > frames {
+            return;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
+    }
+}
+
+/// Draws of a manual indeterminate bar made with `config`, one per tick
+async fn indeterminate_draws(config: BarConfig, ticks: usize) -> Vec<String> {
+    let (log, factory) = recorder();
+    let config = BarConfigBuilder::from(config)
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .charset(throbberous::Charset::Ascii)
+        .refresh_rate(100)
+        .build();
+    let bar = Bar::indeterminate_manual("Waiting", config);
+    for _ in 0..ticks {
+        let frames = bar.render_stats().frames_drawn;
+        bar.tick().await;
+        drawn_after(&bar, frames).await;
+    }
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    log.iter()
+        .filter_map(|call| call.strip_prefix("draw "))
+        .map(|line| line.trim_end_matches(" Waiting").to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_indeterminate_styles() {
+    use throbberous::IndeterminateStyle;
+
+    let draws = |style| {
+        indeterminate_draws(
+            BarConfig::builder()
+                .indeterminate_style(style)
+                .width(8)
+                .build(),
+            7,
+        )
+    };
+    // Bounce turns around at the end, Marquee wraps around it
+    assert_eq!(
+        draws(IndeterminateStyle::Bounce).await[3..],
+        ["[    === ]", "[     ===]", "[    === ]", "[   ===  ]"]
+    );
+    assert_eq!(
+        draws(IndeterminateStyle::Marquee).await[3..],
+        ["[    === ]", "[     ===]", "[=     ==]", "[==     =]"]
+    );
+    assert_eq!(
+        draws(IndeterminateStyle::Pulse).await[..3],
+        ["[   =    ]", "[   ==   ]", "[  ===   ]"]
+    );
+    assert_eq!(
+        draws(IndeterminateStyle::Scan).await[..3],
+        ["[=#=-.   ]", "[-=#=-.  ]", "[.-=#=-. ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_indeterminate_custom_animator() {
+    let config = BarConfig::builder()
+        .animator(Some(throbberous::shared_animator(
+            |tick: u64, width: usize| "~-".repeat((tick as usize % width) + 1),
+        )))
+        .width(8)
+        .build();
+    // Frames that are too short are padded and ones too long cut to the width
+    assert_eq!(
+        indeterminate_draws(config, 4).await,
+        ["[~-~-    ]", "[~-~-~-  ]", "[~-~-~-~-]", "[~-~-~-~-]"]
+    );
+}
+
+#[tokio::test]
+async fn test_bar_finish_with_status() {
+    let (log, factory) = recorder();
+    let theme = throbberous::StatusTheme::ascii();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .status_theme(theme.clone())
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.inc(3).await;
+    let report = bar
+        .finish_with_status(throbberous::Status::Warning, "3 of 10 skipped")
+        .await;
+    assert_eq!(report.total, 3);
+    assert_eq!(report.message, "3 of 10 skipped");
+
+    // The bar stays where it stopped instead of filling up
+    assert_eq!(
+        log.lock().unwrap().last().unwrap(),
+        &format!(
+            "finish {} [===       ] 30% 3 of 10 skipped",
+            theme.warning.symbol
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_throbber_custom_finish_styles() {
+    let (log, factory) = recorder();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .status_theme(throbberous::StatusTheme {
+            warning: throbberous::StatusStyle::new("[!]", None),
+            ..throbberous::StatusTheme::ascii()
+        })
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.start().await;
+    throbber.stop_warn("2 packages yanked").await;
+    assert_eq!(
+        log.lock().unwrap().last().unwrap(),
+        "finish [!] 2 packages yanked"
+    );
+
+    throbber.start().await;
+    throbber
+        .stop_with("»", Some(crossterm::style::Color::Blue), "Skipped")
+        .await;
+    assert_eq!(log.lock().unwrap().last().unwrap(), "finish » Skipped");
+}
+
+#[tokio::test]
+async fn test_default_status_symbols_follow_unicode_support() {
+    use throbberous::{capabilities, StatusTheme};
+
+    let theme = StatusTheme::default();
+    if capabilities::supports_unicode() {
+        assert_eq!(theme, StatusTheme::unicode());
+    } else {
+        assert_eq!(theme, StatusTheme::ascii());
+    }
+    let ascii = StatusTheme::ascii();
+    assert!([
+        ascii.success,
+        ascii.warning,
+        ascii.error,
+        ascii.cancelled,
+        ascii.timeout
+    ]
+    .iter()
+    .all(|style| style.symbol.is_ascii()));
+
+    let (log, factory) = recorder();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.start().await;
+    throbber.stop_err("Build failed").await;
+    assert_eq!(
+        log.lock().unwrap().last().unwrap(),
+        &format!("finish {} Build failed", theme.error.symbol)
+    );
+}
+
+#[tokio::test]
+async fn test_bar_shrinks_to_fit_the_terminal() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .width(500)
+        .charset(throbberous::Charset::Ascii)
+        .build();
+    let bar = Bar::with_config(4, config);
+    bar.set_message("Compiling a crate with a rather long name")
+        .await;
+    bar.inc(2).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    let line = log[0].strip_prefix("draw ").unwrap();
+    let cells = line.find(']').unwrap() - 1;
+    // Without a terminal there is nothing to fit, `width` is used as is
+    match throbberous::capabilities::terminal_width() {
+        Some(columns) => {
+            assert!(line.len() <= columns.max(10), "{:?} in {}", line, columns);
+            assert!(cells >= 10);
+        }
+        None => assert_eq!(cells, 500),
+    }
+}
+
+#[tokio::test]
+async fn test_ascii_charset_replaces_block_characters() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .smooth(true)
+        .charset(throbberous::Charset::Ascii)
+        .build();
+    let bar = Bar::with_config(4, config);
+    bar.inc(2).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(log.iter().any(|call| call.starts_with("draw [#####-----]")));
+    assert!(log.last().unwrap().starts_with("finish [##########]"));
+    assert!(log.iter().all(|call| call.is_ascii()));
+}
+
+#[tokio::test]
+async fn test_spinner_preset_draws_its_frames_in_order() {
+    let (log, factory) = recorder();
+    let config = ThrobberConfigBuilder::from(ThrobberConfig::dots())
+        .renderer(Some(factory))
+        .charset(throbberous::Charset::Unicode)
+        .build();
+    let throbber = Throbber::manual(config);
+    throbber.set_message("Linking").await;
+    throbber.start().await;
+    for _ in 0..2 {
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        throbber.tick().await;
+    }
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    throbber.stop().await;
+
+    let log = log.lock().unwrap();
+    let drawn: Vec<&str> = log
+        .iter()
+        .filter_map(|call| call.strip_prefix("draw "))
+        .collect();
+    let expected: Vec<String> = throbberous::presets::DOTS[..3]
+        .iter()
+        .map(|frame| format!("{} Linking", frame))
+        .collect();
+    assert_eq!(drawn, expected);
+}
+
+#[tokio::test]
+async fn test_ascii_charset_replaces_spinner_frames() {
+    let (log, factory) = recorder();
+    let config = ThrobberConfigBuilder::from(ThrobberConfig::dots())
+        .renderer(Some(factory))
+        .charset(throbberous::Charset::Ascii)
+        .frame_delay(20)
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("Linking").await;
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    throbber.stop().await;
+
+    let log = log.lock().unwrap();
+    assert!(log.iter().any(|call| call.starts_with("draw ")));
+    assert!(log.iter().all(|call| call.is_ascii()));
+}
+
+#[tokio::test]
+async fn test_detail_line_under_bar() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .build();
+    let bar = Bar::with_config(2, config);
+    bar.set_message("Indexing").await;
+    bar.set_detail("src/lib.rs").await;
+    bar.inc(1).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(log
+        .iter()
+        .any(|call| call.starts_with("draw [=====     ]") && call.ends_with("\n  src/lib.rs")));
+    // The final frame goes back to a single line
+    assert!(!log.last().unwrap().contains('\n'));
+}
+
+#[tokio::test]
+async fn test_spinner_in_front_of_determinate_bar() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .spinner(Some(vec!["*".to_string()]))
+        .build();
+    let bar = Bar::with_config(4, config);
+    // No progress at all, the animate task still redraws the spinner
+    tokio::time::sleep(tokio::time::Duration::from_millis(250)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    let draws = log
+        .iter()
+        .filter(|call| call.starts_with("draw * [          ] 0%"))
+        .count();
+    assert!(draws >= 2, "{log:?}");
+    assert!(log.last().unwrap().starts_with("finish [==========] 100%"));
+}
+
+#[tokio::test]
+async fn test_weighted_bar_counts_items() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .count_display(throbberous::CountDisplay::WithPercent)
+        .count_label(Some("files".to_string()))
+        .build();
+    let bar = Bar::weighted_with_config([900, 50, 50], config);
+    bar.inc_weighted(900).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert_eq!(bar.items_done(), 1);
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(log
+        .iter()
+        .any(|call| call.starts_with("draw [========= ] 90% 1/3 files")));
+    assert!(log
+        .last()
+        .unwrap()
+        .starts_with("finish [==========] 100% 3/3 files"));
+}
+
+#[tokio::test]
+async fn test_right_to_left_bar_fills_from_the_right() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .head_char(Some('<'))
+        .right_to_left(true)
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.inc(3).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(log.iter().any(|call| call.starts_with("draw [       <==]")));
+    assert!(log.last().unwrap().starts_with("finish [==========]"));
+}
+
+/// Keeps the segments of the last frame drawn or finished
+struct LastSegments(Arc<Mutex<Vec<Segment>>>);
+
+impl Renderer for LastSegments {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        *self.0.lock().unwrap() = lines.concat();
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.draw(lines);
+    }
+
+    fn clear(&mut self) {}
+}
+
+#[tokio::test]
+async fn test_theme_styles_filled_empty_and_message_separately() {
+    use crossterm::style::Color;
+    use throbberous::{TextStyle, Theme};
+
+    let segments = Arc::new(Mutex::new(Vec::new()));
+    let shared = segments.clone();
+    let filled_style = TextStyle {
+        bold: true,
+        ..TextStyle::default()
+    };
+    let empty_style = TextStyle {
+        background: Some(Color::DarkGrey),
+        ..TextStyle::default()
+    };
+    let message_style = TextStyle {
+        dim: true,
+        italic: true,
+        ..TextStyle::default()
+    };
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(Arc::new(move || {
+            Box::new(LastSegments(shared.clone()))
+        })))
+        .fit_to_terminal(false)
+        .width(10)
+        .theme(Theme {
+            filled_style,
+            empty_style,
+            message_style,
+            ..Theme::default()
+        })
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.set_message("Copying").await;
+    bar.inc(4).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    let styled = |text: &str| {
+        segments
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|segment| segment.text == text)
+            .map(|segment| segment.style)
+    };
+    assert_eq!(styled("===="), Some(filled_style));
+    assert_eq!(styled("      "), Some(empty_style));
+    assert_eq!(styled("Copying"), Some(message_style));
+    bar.finish().await;
+}
+
+/// Keeps the segments of every frame drawn
+struct AllSegments(Arc<Mutex<Vec<Vec<Segment>>>>);
+
+impl Renderer for AllSegments {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.0.lock().unwrap().push(lines.concat());
+    }
+
+    fn finish(&mut self, _lines: &[Vec<Segment>]) {}
+
+    fn clear(&mut self) {}
+}
+
+#[tokio::test]
+async fn test_message_color_stays_fixed_while_bar_cycles() {
+    use crossterm::style::Color;
+    use throbberous::{TextStyle, Theme};
+
+    let frames = Arc::new(Mutex::new(Vec::new()));
+    let shared = frames.clone();
+    let message_style = TextStyle {
+        dim: true,
+        ..TextStyle::default()
+    };
+    let config = BarConfig::builder()
+        .renderer(Some(Arc::new(move || {
+            Box::new(AllSegments(shared.clone()))
+        })))
+        .fit_to_terminal(false)
+        .width(10)
+        .colors(Some(vec![Color::Red, Color::Blue]))
+        .color_cycle_delay(150)
+        .theme(Theme {
+            message: Some(Color::White),
+            message_style,
+            ..Theme::default()
+        })
+        .build();
+    let bar = Bar::with_config(20, config);
+    bar.set_message("Indexing").await;
+    for _ in 0..15 {
+        bar.inc(1).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    }
+
+    {
+        let frames = frames.lock().unwrap();
+        let bar_colors: std::collections::HashSet<_> = frames
+            .iter()
+            .filter_map(|segments| segments.iter().find(|s| s.text.starts_with('=')))
+            .map(|segment| format!("{:?}", segment.color))
+            .collect();
+        assert!(bar_colors.len() > 1, "bar should cycle colors");
+        for segments in frames.iter() {
+            let message = segments.iter().find(|s| s.text == "Indexing").unwrap();
+            assert_eq!(message.color, Some(Color::White));
+            assert_eq!(message.style, message_style);
+        }
+    }
+    bar.finish().await;
+}
+
+/// A two-color bar at 5/20, drawing every frame into the returned list
+fn cycling_bar(color_cycle_delay: u64) -> (Bar, Arc<Mutex<Vec<Vec<Segment>>>>) {
+    use crossterm::style::Color;
+
+    let frames = Arc::new(Mutex::new(Vec::new()));
+    let shared = frames.clone();
+    let config = BarConfig::builder()
+        .renderer(Some(Arc::new(move || {
+            Box::new(AllSegments(shared.clone()))
+        })))
+        .fit_to_terminal(false)
+        .width(10)
+        .colors(Some(vec![Color::Red, Color::Blue]))
+        .color_cycle_delay(color_cycle_delay)
+        .build();
+    (Bar::with_config(20, config), frames)
+}
+
+/// How many colors the `=` fill was drawn in
+fn fill_colors(frames: &Mutex<Vec<Vec<Segment>>>) -> usize {
+    frames
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|segments| segments.iter().find(|s| s.text.starts_with('=')))
+        .map(|segment| format!("{:?}", segment.color))
+        .collect::<std::collections::HashSet<_>>()
+        .len()
+}
+
+#[tokio::test]
+async fn test_stalled_bar_keeps_cycling_colors() {
+    let (bar, frames) = cycling_bar(150);
+    bar.inc(5).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(400)).await;
+    bar.finish().await;
+    assert!(fill_colors(&frames) > 1, "stalled bar should cycle colors");
+}
+
+#[tokio::test]
+async fn test_busy_bar_cycles_colors_at_its_own_pace() {
+    let (bar, frames) = cycling_bar(60_000);
+    for _ in 0..10 {
+        bar.inc(2).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(fill_colors(&frames), 1);
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_bar_splits_succeeded_and_failed_items() {
+    use crossterm::style::Color;
+
+    let segments = Arc::new(Mutex::new(Vec::new()));
+    let shared = segments.clone();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(Arc::new(move || {
+            Box::new(LastSegments(shared.clone()))
+        })))
+        .fit_to_terminal(false)
+        .width(10)
+        .status_theme(throbberous::StatusTheme::ascii())
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.inc_ok(6).await;
+    bar.handle().inc_failed(2);
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    {
+        let segments = segments.lock().unwrap();
+        let text: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert!(text.starts_with("[========  ] 80% 6 ok, 2 failed "));
+        let cells: Vec<_> = segments
+            .iter()
+            .filter(|s| s.text.starts_with('='))
+            .map(|s| (s.text.as_str(), s.color))
+            .collect();
+        assert_eq!(
+            cells,
+            [("======", Some(Color::Green)), ("==", Some(Color::Red))]
+        );
+    }
+    assert_eq!((bar.succeeded(), bar.failed()), (6, 2));
+    bar.finish().await;
+}
+
+#[tokio::test]
+async fn test_finished_line_shows_average_rate() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .units(throbberous::Units::Bytes)
+        .unit_precision(2)
+        .show_rate(true)
+        .build();
+    let bar = Bar::with_config(4096, config);
+    bar.inc(4096).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    let last = log.last().unwrap();
+    assert!(
+        last.starts_with("finish [==========] 100% 4.00 KiB / 4.00 KiB avg "),
+        "{}",
+        last
+    );
+    assert!(last.contains("/s"), "{}", last);
+}
+
+#[tokio::test]
+async fn test_built_in_text_comes_from_strings() {
+    let strings = throbberous::Strings {
+        throbbing: "Patientez...".to_string(),
+        average: "moy.".to_string(),
+        ..throbberous::Strings::default()
+    };
+
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .show_rate(true)
+        .strings(strings.clone())
+        .build();
+    let bar = Bar::with_config(4, config);
+    bar.inc(4).await;
+    bar.finish().await;
+    let last = log.lock().unwrap().last().unwrap().clone();
+    assert!(
+        last.starts_with("finish [==========] 100% moy. "),
+        "{}",
+        last
+    );
+
+    let (log, factory) = recorder();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .strings(strings)
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    throbber.stop().await;
+    assert!(log
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|call| call.contains("Patientez...")));
+}
+
+#[tokio::test]
+async fn test_percent_precision_and_hidden_percent() {
+    let line = |show_percent, percent_precision| {
+        let (log, factory) = recorder();
+        let config = BarConfig::builder()
+            .colors(None)
+            .renderer(Some(factory))
+            .fit_to_terminal(false)
+            .width(10)
+            .show_percent(show_percent)
+            .percent_precision(percent_precision)
+            .count_display(throbberous::CountDisplay::WithPercent)
+            .build();
+        async move {
+            let bar = Bar::with_config(3_000_000, config);
+            bar.set_message("Indexing").await;
+            bar.inc(1_269_000).await;
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            let line = log.lock().unwrap().last().unwrap().clone();
+            bar.finish().await;
+            line
+        }
+    };
+
+    assert_eq!(
+        line(true, 1).await,
+        "draw [====      ] 42.3% 1,269,000/3,000,000 Indexing"
+    );
+    assert_eq!(
+        line(false, 1).await,
+        "draw [====      ] 1,269,000/3,000,000 Indexing"
+    );
+}
+
+#[tokio::test]
+async fn test_formatter_replaces_the_line() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .formatter(Some(Arc::new(
+            |snapshot: &throbberous::ProgressSnapshot| {
+                format!(
+                    "{} of {} {}",
+                    snapshot.position,
+                    snapshot.total.unwrap_or_default(),
+                    snapshot.message
+                )
+            },
+        )))
+        .build();
+    let bar = Bar::with_config(8, config);
+    bar.set_message("chunks").await;
+    bar.inc(3).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(log.contains(&"draw 3 of 8 chunks".to_string()), "{:?}", log);
+    assert_eq!(log.last().unwrap(), "finish 8 of 8 chunks");
+}
+
+#[tokio::test]
+async fn test_manual_tick_drives_throbber_and_bar() {
+    let (log, factory) = recorder();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .frames(["a", "b", "c"].map(String::from).to_vec())
+        .frame_delay(10)
+        .build();
+    let throbber = Throbber::manual(config);
+    throbber.set_message("Working").await;
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(*log.lock().unwrap(), ["draw a Working"]);
+
+    throbber.tick().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert_eq!(log.lock().unwrap().last().unwrap(), "draw b Working");
+
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .build();
+    let bar = Bar::indeterminate_manual("Waiting", config);
+    tokio::time::sleep(tokio::time::Duration::from_millis(250)).await;
+    assert!(log.lock().unwrap().is_empty());
+
+    // A frame apart at the default refresh rate
+    for _ in 0..2 {
+        bar.tick().await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    }
+    let log = log.lock().unwrap();
+    assert_eq!(log.len(), 2);
+    assert_ne!(log[0], log[1]);
+}
+
+#[tokio::test]
+async fn test_refresh_rate_caps_frames_drawn() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .refresh_rate(5)
+        .build();
+    let bar = Bar::with_config(50, config);
+    for _ in 0..50 {
+        bar.inc(1).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+    }
+    let draws = log.lock().unwrap().len();
+    assert!((2..10).contains(&draws), "{} frames in about 500ms", draws);
+    assert!(bar.render_stats().frames_skipped > 40);
+}
+
+#[tokio::test]
+async fn test_suspend_pauses_drawing() {
+    let (log, factory) = recorder();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .build();
+    let bar = Bar::with_config(4, config);
+    bar.inc(1).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    let before = log.lock().unwrap().len();
+
+    let suspended = bar.clone();
+    let answer = tokio::spawn(async move {
+        suspended
+            .suspend_async(|| async {
+                suspended.set_message("Resumed").await;
+                suspended.inc(1).await;
+                tokio::time::sleep(tokio::time::Duration::from_millis(250)).await;
+                42
+            })
+            .await
+    });
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    assert_eq!(log.lock().unwrap().len(), before);
+    assert_eq!(answer.await.unwrap(), 42);
+
+    assert_eq!(bar.suspend(|| "prompt answered").await, "prompt answered");
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    assert_eq!(
+        log.lock().unwrap().last().unwrap(),
+        "draw [=====     ] 50% Resumed"
+    );
+}
+
+#[tokio::test]
+async fn test_spinner_after_message_or_at_column() {
+    async fn lines(placement: throbberous::SpinnerPlacement) -> Vec<String> {
+        let (log, factory) = recorder();
+        let config = ThrobberConfig::builder()
+            .colors(None)
+            .renderer(Some(factory))
+            .frames(vec!["*".to_string()])
+            .spinner_placement(placement)
+            .status_theme(throbberous::StatusTheme::ascii())
+            .fit_to_terminal(false)
+            .build();
+        let throbber = Throbber::with_config(config);
+        throbber.set_message("Building").await;
+        throbber.start().await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        throbber.stop_success("Built").await;
+        let log = log.lock().unwrap().clone();
+        log
+    }
+
+    use throbberous::SpinnerPlacement;
+    assert_eq!(
+        lines(SpinnerPlacement::After).await,
+        ["draw Building *", "finish Built OK"]
+    );
+    assert_eq!(
+        lines(SpinnerPlacement::Column(12)).await,
+        ["draw Building    *", "finish Built       OK"]
+    );
+    // A message reaching past the column pushes the glyph right
+    assert_eq!(
+        lines(SpinnerPlacement::Column(4)).await,
+        ["draw Building *", "finish Built OK"]
+    );
+}
+
+#[tokio::test]
+async fn test_no_milestone_messages_unless_provided() {
+    let line = |message_provider| {
+        let (log, factory) = recorder();
+        let config = BarConfig::builder()
+            .colors(None)
+            .renderer(Some(factory))
+            .fit_to_terminal(false)
+            .width(10)
+            .message_provider(message_provider)
+            .build();
+        async move {
+            let bar = Bar::with_config(4, config);
+            bar.inc(2).await;
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            let line = log.lock().unwrap().last().unwrap().clone();
+            bar.finish().await;
+            line
+        }
+    };
+
+    assert_eq!(line(None).await.trim_end(), "draw [=====     ] 50%");
+    assert_eq!(
+        line(Some(Arc::new(|progress: f64| format!(
+            "{:.0}% erledigt",
+            progress * 100.0
+        ))))
+        .await,
+        "draw [=====     ] 50% 50% erledigt"
+    );
+    assert_eq!(
+        line(Some(Arc::new(Bar::milestone_message))).await,
+        "draw [=====     ] 50% Halfway done"
+    );
+}
+
+#[tokio::test]
+async fn test_throbber_builder_starts_with_its_message() {
+    let (log, factory) = recorder();
+    let throbber = Throbber::builder()
+        .config(
+            ThrobberConfig::builder()
+                .colors(None)
+                .renderer(Some(factory))
+                .frames(vec!["*".to_string()])
+                .build(),
+        )
+        .message("Resolving dependencies")
+        .auto_start(true)
+        .spawn();
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert_eq!(
+        log.lock().unwrap().first().unwrap(),
+        "draw * Resolving dependencies"
+    );
+    throbber.stop_success("Resolved").await;
+
+    let log = log.lock().unwrap();
+    assert!(
+        log.iter().all(|call| !call.contains("Throbbing")),
+        "{:?}",
+        log
+    );
+}
+
+#[tokio::test]
+async fn test_rocket_bar_keeps_its_width_with_a_wide_head() {
+    let (log, factory) = recorder();
+    let config = BarConfigBuilder::from(BarConfig::rocket())
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(10)
+        .show_percent(false)
+        .count_display(throbberous::CountDisplay::Hidden)
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.inc(5).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(
+        log.iter().any(|call| call.starts_with("draw [~~~~🚀····]")),
+        "{:?}",
+        log
+    );
+}
+
+#[tokio::test]
+async fn test_pacman_bar_chomps_across_when_indeterminate() {
+    let (log, factory) = recorder();
+    let config = BarConfigBuilder::from(BarConfig::pacman())
+        .renderer(Some(factory))
+        .fit_to_terminal(false)
+        .width(6)
+        .refresh_rate(100)
+        .colors(None)
+        .build();
+    let bar = Bar::indeterminate_with_config("Eating", config);
+    tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+    bar.finish().await;
+
+    let log = log.lock().unwrap();
+    assert!(log.iter().any(|call| call.starts_with("draw [ᗧ•••••]")));
+    assert!(log.iter().any(|call| call.starts_with("draw [ ●••••]")));
+}
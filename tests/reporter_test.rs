@@ -0,0 +1,45 @@
+use throbberous::{Bar, BarConfig, NoopReporter, OutputMode, ProgressReporter, Throbber};
+
+async fn process_items(reporter: &dyn ProgressReporter) {
+    reporter.set_message("Processing").await;
+    for _ in 0..10 {
+        reporter.inc(1).await;
+    }
+    reporter.finish().await;
+}
+
+fn hidden_bar() -> Bar {
+    Bar::with_config(10, BarConfig::builder().output(OutputMode::Hidden).build())
+}
+
+#[tokio::test]
+async fn test_reporter_is_object_safe() {
+    process_items(&NoopReporter).await;
+
+    let bar = hidden_bar();
+    process_items(&bar as &dyn ProgressReporter).await;
+    assert_eq!(bar.position(), 10);
+    assert!(bar.snapshot().await.finished);
+    // Finishing again only reports on the run that already ended
+    let report = bar.finish().await;
+    assert_eq!(report.total, 10);
+    assert_eq!(report.message, "Processing");
+
+    let throbber = Throbber::new_plain();
+    throbber.start().await;
+    process_items(&throbber).await;
+}
+
+#[tokio::test]
+async fn test_boxed_reporter_chosen_at_runtime() {
+    for quiet in [true, false] {
+        let bar = hidden_bar();
+        let reporter: Box<dyn ProgressReporter> = if quiet {
+            Box::new(NoopReporter)
+        } else {
+            Box::new(bar.clone())
+        };
+        process_items(&reporter).await;
+        assert_eq!(bar.position(), if quiet { 0 } else { 10 });
+    }
+}
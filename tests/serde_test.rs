@@ -0,0 +1,89 @@
+#![cfg(feature = "serde")]
+
+use crossterm::style::Color;
+use throbberous::{
+    presets::Spinner, BarConfig, ColorMode, FinishAlert, Theme, ThrobberConfig,
+    ThrobberConfigBuilder, Units,
+};
+
+#[test]
+fn test_bar_config_from_partial_json() {
+    let config: BarConfig = serde_json::from_str(
+        r##"{
+            "width": 30,
+            "units": "decimal_bytes",
+            "filled_char": "#",
+            "color_mode": { "gradient": { "from": "dark_red", "to": "green" } },
+            "theme": { "brackets": "dark_grey" },
+            "alert": { "bell": true }
+        }"##,
+    )
+    .unwrap();
+
+    assert_eq!(config.width, 30);
+    assert_eq!(config.units, Units::DecimalBytes);
+    assert_eq!(config.filled_char, '#');
+    assert_eq!(
+        config.color_mode,
+        ColorMode::Gradient {
+            from: Color::DarkRed,
+            to: Color::Green
+        }
+    );
+    assert_eq!(config.theme.brackets, Some(Color::DarkGrey));
+    assert_eq!(config.alert, FinishAlert::bell());
+    // Fields left out keep their defaults
+    assert_eq!(config.left_bracket, "[");
+    assert!(config.fit_to_terminal);
+}
+
+#[test]
+fn test_throbber_config_round_trip() {
+    let config = ThrobberConfigBuilder::from(ThrobberConfig::from(Spinner::Arc))
+        .theme(Theme::ocean())
+        .show_elapsed(true)
+        .build();
+    let json = serde_json::to_string(&config).unwrap();
+    let loaded: ThrobberConfig = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(loaded.frames, config.frames);
+    assert_eq!(loaded.frame_delay, 100);
+    assert_eq!(loaded.theme, config.theme);
+    assert!(loaded.show_elapsed);
+}
+
+#[test]
+fn test_spinner_preset_by_name() {
+    let spinner: Spinner = serde_json::from_str(r#""bouncing_ball""#).unwrap();
+    assert_eq!(spinner, Spinner::BouncingBall);
+
+    let config = ThrobberConfig::from(spinner);
+    assert_eq!(config.frames[0], "( ●    )");
+}
+
+#[tokio::test]
+async fn test_bar_resumes_from_saved_snapshot() {
+    let bar = throbberous::Bar::with_config(200, BarConfig::no_colors());
+    bar.set_message("Indexing").await;
+    bar.set_detail("docs/intro.md").await;
+    bar.inc(80).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    let saved = serde_json::to_string(&bar.snapshot().await).unwrap();
+    drop(bar);
+
+    let snapshot: throbberous::ProgressSnapshot = serde_json::from_str(&saved).unwrap();
+    let resumed =
+        throbberous::Bar::from_snapshot(snapshot.clone(), BarConfig::no_colors()).unwrap();
+    let restored = resumed.snapshot().await;
+    assert_eq!(restored.position, 80);
+    assert_eq!(restored.total, Some(200));
+    assert_eq!(restored.message, "Indexing");
+    assert_eq!(restored.detail.as_deref(), Some("docs/intro.md"));
+    assert!(restored.elapsed >= snapshot.elapsed);
+    assert!(snapshot.elapsed >= std::time::Duration::from_millis(50));
+
+    resumed.inc(120).await;
+    let report = resumed.finish().await;
+    assert_eq!(report.total, 200);
+    assert!(report.elapsed >= snapshot.elapsed);
+}
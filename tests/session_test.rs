@@ -0,0 +1,89 @@
+#[test]
+fn test_session_restores_panic_hook() {
+    let session = throbberous::init();
+    let caught = std::panic::catch_unwind(|| panic!("inside session"));
+    assert!(caught.is_err());
+    drop(session);
+}
+
+#[tokio::test]
+#[ignore = "run by test_session_with_signal_handler"]
+async fn child_session_waiting_for_signal() {
+    use std::io::Write;
+
+    let _session = throbberous::init().handle_signals();
+    let config = throbberous::ThrobberConfig::builder()
+        .colors(None)
+        .output(throbberous::OutputMode::Terminal)
+        .build();
+    let throbber = throbberous::Throbber::with_config(config);
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "READY").unwrap();
+    stdout.flush().unwrap();
+    drop(stdout);
+    // Ended by the signal long before this
+    tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
+}
+
+#[cfg(unix)]
+#[test]
+fn test_session_with_signal_handler() {
+    use std::{
+        io::{BufRead, BufReader, Read},
+        process::{Command, Stdio},
+    };
+
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "child_session_waiting_for_signal",
+            "--exact",
+            "--include-ignored",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    while !line.contains("READY") {
+        line.clear();
+        assert!(
+            stdout.read_line(&mut line).unwrap() > 0,
+            "child exited early"
+        );
+    }
+
+    let killed = Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(killed.success());
+    let status = child.wait().unwrap();
+    let mut stderr = Vec::new();
+    child
+        .stderr
+        .take()
+        .unwrap()
+        .read_to_end(&mut stderr)
+        .unwrap();
+
+    // 128 + SIGTERM, after clearing the line, resetting colors and showing the cursor
+    assert_eq!(status.code(), Some(143));
+    let stderr = String::from_utf8_lossy(&stderr);
+    assert!(
+        stderr.ends_with("\x1b[1G\x1b[2K\x1b[0m\x1b[?25h"),
+        "{:?}",
+        stderr
+    );
+}
+
+#[test]
+fn test_install_panic_hook_is_idempotent() {
+    throbberous::install_panic_hook();
+    throbberous::install_panic_hook();
+    let caught = std::panic::catch_unwind(|| panic!("after hook"));
+    assert!(caught.is_err());
+}
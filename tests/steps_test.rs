@@ -0,0 +1,79 @@
+use std::sync::{Arc, Mutex};
+
+use throbberous::{Renderer, Segment, Steps, ThrobberConfig};
+
+/// Keeps the last frame drawn or finished as plain text
+struct LastFrame(Arc<Mutex<String>>);
+
+impl LastFrame {
+    fn store(&mut self, lines: &[Vec<Segment>]) {
+        *self.0.lock().unwrap() = lines
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_str()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+}
+
+impl Renderer for LastFrame {
+    fn draw(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn finish(&mut self, lines: &[Vec<Segment>]) {
+        self.store(lines);
+    }
+
+    fn clear(&mut self) {
+        self.store(&[]);
+    }
+}
+
+fn steps(names: &[&str]) -> (Arc<Mutex<String>>, Steps) {
+    let frame = Arc::new(Mutex::new(String::new()));
+    let shared = frame.clone();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .frames(vec!["*".to_string()])
+        .status_theme(throbberous::StatusTheme::ascii())
+        .fit_to_terminal(false)
+        .renderer(Some(Arc::new(move || Box::new(LastFrame(shared.clone())))))
+        .build();
+    (frame, Steps::with_config(names.iter().copied(), config))
+}
+
+#[tokio::test]
+async fn test_steps_advance_in_order() {
+    let (frame, steps) = steps(&["Fetching", "Compiling", "Linking"]);
+
+    steps.next("Fetching").await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert_eq!(*frame.lock().unwrap(), "* Fetching\n  Compiling\n  Linking");
+
+    steps.next("Compiling").await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert_eq!(
+        *frame.lock().unwrap(),
+        "OK Fetching\n* Compiling\n  Linking"
+    );
+
+    steps.next("Linking").await;
+    steps.finish().await;
+    assert_eq!(
+        *frame.lock().unwrap(),
+        "OK Fetching\nOK Compiling\nOK Linking"
+    );
+}
+
+#[tokio::test]
+async fn test_steps_fail_and_undeclared_steps() {
+    let (frame, steps) = steps(&["Fetching", "Linking"]);
+
+    steps.next("Fetching").await;
+    steps.next("Compiling").await;
+    steps.fail().await;
+    assert_eq!(
+        *frame.lock().unwrap(),
+        "OK Fetching\nFAIL Compiling\n  Linking"
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "stream")]
+
+use std::{
+    future::poll_fn,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use throbberous::{Bar, BarConfig};
+
+/// Yields its chunks, telling how many are left only if `sized`
+struct Chunks {
+    chunks: Vec<Vec<u8>>,
+    sized: bool,
+}
+
+impl Stream for Chunks {
+    type Item = Vec<u8>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Vec<u8>>> {
+        Poll::Ready((!self.chunks.is_empty()).then(|| self.chunks.remove(0)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.sized {
+            true => (self.chunks.len(), Some(self.chunks.len())),
+            false => (0, None),
+        }
+    }
+}
+
+fn chunks(sized: bool) -> Chunks {
+    Chunks {
+        chunks: vec![vec![0; 3], vec![0; 5], vec![0; 2]],
+        sized,
+    }
+}
+
+async fn drain<S: Stream + Unpin>(stream: &mut S) -> usize {
+    let mut items = 0;
+    while poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx))
+        .await
+        .is_some()
+    {
+        items += 1;
+    }
+    items
+}
+
+#[tokio::test]
+async fn test_sized_stream_gets_a_determinate_bar() {
+    let mut stream = Bar::wrap_sized_stream(chunks(true));
+    assert_eq!(stream.bar().snapshot().await.total, Some(3));
+    assert_eq!(drain(&mut stream).await, 3);
+
+    let snapshot = stream.bar().snapshot().await;
+    assert_eq!(snapshot.position, 3);
+    assert!(snapshot.finished);
+}
+
+#[tokio::test]
+async fn test_unsized_stream_gets_an_indeterminate_bar() {
+    let mut stream = Bar::wrap_sized_stream(chunks(false));
+    assert_eq!(drain(&mut stream).await, 3);
+
+    let snapshot = stream.bar().snapshot().await;
+    assert_eq!(snapshot.total, None);
+    assert_eq!(snapshot.position, 3);
+    assert!(snapshot.finished);
+}
+
+#[tokio::test]
+async fn test_stream_with_total_weighs_items() {
+    let mut stream = Bar::wrap_stream_with_total(chunks(false), Some(10), BarConfig::no_colors())
+        .weigh_items(|chunk: &Vec<u8>| chunk.len() as u64);
+    assert_eq!(drain(&mut stream).await, 3);
+
+    let snapshot = stream.bar().snapshot().await;
+    assert_eq!(snapshot.position, 10);
+    assert_eq!(snapshot.total, Some(10));
+    assert!(snapshot.finished);
+}
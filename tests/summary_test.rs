@@ -0,0 +1,183 @@
+use throbberous::{
+    collect_summary, summary, Bar, BarConfig, BarConfigBuilder, MultiBar, OutputMode, Status,
+    Strings, Summary, Throbber, ThrobberConfig, ThrobberConfigBuilder, WidgetSummary,
+};
+
+fn hidden() -> BarConfig {
+    BarConfig::builder().output(OutputMode::Hidden).build()
+}
+
+/// The summary's entry for the widget with `id`; other tests add widgets too
+fn entry(summary: &Summary, id: u64) -> &WidgetSummary {
+    summary
+        .widgets
+        .iter()
+        .find(|widget| widget.id == id)
+        .expect("widget is tracked")
+}
+
+#[tokio::test]
+async fn test_summary_records_finished_bars() {
+    collect_summary();
+    let download = Bar::with_config(
+        10,
+        BarConfigBuilder::from(hidden())
+            .label(Some("download".to_string()))
+            .build(),
+    );
+    let checksum = Bar::with_config(10, hidden());
+    let pending = Bar::with_config(10, hidden());
+
+    download.inc(3).await;
+    download.finish().await;
+    checksum.inc(4).await;
+    checksum
+        .finish_with_status(Status::Error, "checksum mismatch")
+        .await;
+    pending.inc(1).await;
+
+    let summary = summary().await;
+    let download = entry(&summary, download.id());
+    assert_eq!(download.label, "download");
+    assert_eq!(download.status, Some(Status::Success));
+    assert_eq!((download.position, download.total), (Some(10), Some(10)));
+    assert!(download.finished.is_some());
+
+    let checksum = entry(&summary, checksum.id());
+    assert_eq!(checksum.label, "checksum mismatch");
+    assert_eq!(checksum.status, Some(Status::Error));
+    assert_eq!(checksum.position, Some(4));
+
+    let still_running = entry(&summary, pending.id());
+    assert_eq!(still_running.status, None);
+    assert_eq!(still_running.finished, None);
+    pending.finish().await;
+}
+
+#[tokio::test]
+async fn test_summary_records_throbbers() {
+    collect_summary();
+    let config = ThrobberConfig::builder()
+        .output(OutputMode::Hidden)
+        .label(Some("migrate".to_string()))
+        .build();
+    let migrate = Throbber::with_config(config.clone());
+    let cleanup = Throbber::with_config(ThrobberConfigBuilder::from(config).label(None).build());
+
+    migrate.start().await;
+    migrate.stop_with_status(Status::Warning, "2 skipped").await;
+    cleanup.start().await;
+    cleanup.set_message("cleaning up").await;
+    cleanup.stop().await;
+
+    let summary = summary().await;
+    let migrate = entry(&summary, migrate.id());
+    assert_eq!(migrate.label, "migrate");
+    assert_eq!(migrate.status, Some(Status::Warning));
+    assert_eq!(migrate.position, None);
+
+    let cleanup = entry(&summary, cleanup.id());
+    assert_eq!(cleanup.label, "cleaning up");
+    assert_eq!(cleanup.status, None);
+    assert!(cleanup.finished.is_some());
+}
+
+#[tokio::test]
+async fn test_summary_lists_multi_bar_members_by_label() {
+    collect_summary();
+    let multi = MultiBar::with_config(
+        BarConfigBuilder::from(hidden())
+            .label(Some("shared".to_string()))
+            .build(),
+    );
+    let jobs: Vec<_> = (1..=3)
+        .map(|job| multi.add(2, format!("job {job}")))
+        .collect();
+    for job in &jobs {
+        job.inc(2).await;
+    }
+    multi.finish().await;
+
+    let summary = summary().await;
+    for (index, job) in jobs.iter().enumerate() {
+        let job = entry(&summary, job.id());
+        assert_eq!(job.label, format!("job {}", index + 1));
+        assert_eq!(job.status, Some(Status::Success));
+    }
+}
+
+#[tokio::test]
+async fn test_summary_prints_as_table() {
+    collect_summary();
+    let bar = Bar::with_config(
+        5,
+        BarConfigBuilder::from(hidden())
+            .label(Some("table-bar".to_string()))
+            .build(),
+    );
+    bar.inc(2).await;
+    bar.finish_with_status(Status::Cancelled, "stopped").await;
+
+    let table = summary().await.to_string();
+    let mut lines = table.lines();
+    let header = lines.next().unwrap();
+    assert!(header
+        .split_whitespace()
+        .eq(["ID", "WIDGET", "STATUS", "PROGRESS", "TIME"]));
+    let row = table
+        .lines()
+        .find(|line| line.contains("table-bar"))
+        .expect("row for the bar");
+    let cells: Vec<&str> = row.split_whitespace().collect();
+    assert_eq!(
+        cells[..4],
+        [&bar.id().to_string(), "table-bar", "cancelled", "2/5"]
+    );
+}
+
+#[test]
+fn test_summary_table_takes_strings() {
+    let started = std::time::Instant::now();
+    let summary = Summary {
+        widgets: vec![
+            WidgetSummary {
+                id: 1,
+                label: "paquets".to_string(),
+                status: Some(Status::Error),
+                position: Some(3),
+                total: Some(8),
+                started,
+                finished: Some(started),
+            },
+            WidgetSummary {
+                id: 2,
+                label: "index".to_string(),
+                status: None,
+                position: None,
+                total: None,
+                started,
+                finished: None,
+            },
+        ],
+    };
+    let strings = Strings {
+        column_widget: "TÂCHE".to_string(),
+        column_status: "ÉTAT".to_string(),
+        status_error: "erreur".to_string(),
+        status_running: "en cours".to_string(),
+        ..Strings::default()
+    };
+
+    let table = summary.table(&strings).to_string();
+    let lines: Vec<&str> = table.lines().collect();
+    assert!(lines[0]
+        .split_whitespace()
+        .eq(["ID", "TÂCHE", "ÉTAT", "PROGRESS", "TIME"]));
+    assert!(lines[1]
+        .split_whitespace()
+        .take(4)
+        .eq(["1", "paquets", "erreur", "3/8"]));
+    assert!(lines[2].contains("en cours"), "{}", lines[2]);
+    // Without strings, the table is in English
+    assert!(summary.to_string().contains(" error "));
+}
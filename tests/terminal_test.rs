@@ -0,0 +1,147 @@
+//! Bytes the terminal renderer writes between frames, captured from a child
+//! process's stderr
+
+use std::{io::Write, process::Command, time::Duration};
+
+use throbberous::{Bar, BarConfig, OutputMode, ProgressSnapshot};
+
+/// Moves to the start of the line and clears it, before every whole line
+const LINE: &str = "\x1b[1G\x1b[2K";
+
+/// Runs the ignored test `name` in a child process, returning what it wrote to
+/// stderr split into one chunk per frame, and to stdout
+fn run_child(name: &str) -> (Vec<String>, String) {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([name, "--exact", "--include-ignored"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "child failed:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let frames = String::from_utf8(output.stderr)
+        .unwrap()
+        .split('\0')
+        .map(str::to_string)
+        .collect();
+    (frames, String::from_utf8(output.stdout).unwrap())
+}
+
+fn frames_of(name: &str) -> Vec<String> {
+    run_child(name).0
+}
+
+/// A bar halfway through with `message`, starting out that way so its first
+/// frame is already the whole line
+fn terminal_bar(message: &str) -> Bar {
+    let config = BarConfig::builder()
+        .colors(None)
+        .output(OutputMode::Terminal)
+        .hide_cursor(false)
+        .fit_to_terminal(false)
+        .refresh_rate(100)
+        .width(10)
+        .build();
+    let snapshot = ProgressSnapshot {
+        position: 2,
+        total: Some(4),
+        message: message.to_string(),
+        ..ProgressSnapshot::default()
+    };
+    Bar::from_snapshot(snapshot, config).unwrap()
+}
+
+/// Waits for `bar` to draw a frame, then marks the end of it on stderr
+async fn frame(bar: &Bar) {
+    let drawn = bar.render_stats().frames_drawn;
+    bar.tick().await;
+    for _ in 0..200 {
+        if bar.render_stats().frames_drawn > drawn {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    // Written around the crate, so the renderer doesn't know about it
+    let mut stderr = std::io::stderr().lock();
+    stderr.write_all(b"\0").unwrap();
+    stderr.flush().unwrap();
+}
+
+#[tokio::test]
+#[ignore = "run by test_terminal_redraws_only_what_changed"]
+async fn child_line_changes() {
+    let bar = terminal_bar("Build a");
+    frame(&bar).await;
+    frame(&bar).await;
+    bar.set_message("Build b").await;
+    frame(&bar).await;
+    bar.set_message("漢字a").await;
+    frame(&bar).await;
+    bar.set_message("漢字b").await;
+    frame(&bar).await;
+    bar.finish().await;
+}
+
+#[test]
+fn test_terminal_redraws_only_what_changed() {
+    let frames = frames_of("child_line_changes");
+    assert_eq!(frames[0], format!("{LINE}[=====     ] 50% Build a"));
+    // An unchanged frame writes nothing
+    assert_eq!(frames[1], "");
+    // Only the tail of the message is redrawn, from column 24
+    assert_eq!(frames[2], "\x1b[24G\x1b[Kb");
+    assert_eq!(frames[3], "\x1b[18G\x1b[K漢字a");
+    // The wide characters before the change take two columns each
+    assert_eq!(frames[4], "\x1b[22G\x1b[Kb");
+}
+
+#[tokio::test]
+#[ignore = "run by test_terminal_grows_and_shrinks_the_block"]
+async fn child_block_changes() {
+    let bar = terminal_bar("Build");
+    frame(&bar).await;
+    bar.set_detail("src/lib.rs").await;
+    frame(&bar).await;
+    bar.clear_detail().await;
+    frame(&bar).await;
+    bar.finish().await;
+}
+
+#[test]
+fn test_terminal_grows_and_shrinks_the_block() {
+    let frames = frames_of("child_block_changes");
+    assert_eq!(frames[0], format!("{LINE}[=====     ] 50% Build"));
+    // Only the new row is written, then the cursor goes back to the first
+    assert_eq!(frames[1], format!("\n{LINE}  src/lib.rs\x1b[1A\x1b[1G"));
+    // Only the row left over is cleared
+    assert_eq!(frames[2], format!("\n{LINE}\x1b[1A\x1b[1G"));
+}
+
+#[tokio::test]
+#[ignore = "run by test_terminal_redraws_whole_frame_after_other_output"]
+async fn child_output_between_frames() {
+    let bar = terminal_bar("Build a");
+    frame(&bar).await;
+    throbberous::println("compiled");
+    let mut stderr = std::io::stderr().lock();
+    stderr.write_all(b"\0").unwrap();
+    drop(stderr);
+    bar.set_message("Build b").await;
+    frame(&bar).await;
+    bar.finish().await;
+}
+
+#[test]
+fn test_terminal_redraws_whole_frame_after_other_output() {
+    let (frames, stdout) = run_child("child_output_between_frames");
+    assert_eq!(frames[0], format!("{LINE}[=====     ] 50% Build a"));
+    assert!(stdout.contains("compiled\n"), "{}", stdout);
+    // Taken down for the line, then drawn again below it
+    assert_eq!(
+        frames[1],
+        format!("\x1b[1G\x1b[J{LINE}[=====     ] 50% Build a")
+    );
+    // Not just the changed tail: the screen may have moved under the old frame
+    assert_eq!(frames[2], format!("{LINE}[=====     ] 50% Build b"));
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "test-util")]
+
+use throbberous::{Bar, BarConfig, TestRenderer, Throbber, ThrobberConfig};
+
+#[tokio::test]
+async fn test_renderer_captures_bar_frames() {
+    let output = TestRenderer::new();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(output.factory()))
+        .fit_to_terminal(false)
+        .width(10)
+        .build();
+    let bar = Bar::with_config(100, config);
+    bar.inc(50).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert!(output.last_frame().unwrap().starts_with("[=====     ] 50%"));
+    assert!(!output.is_finished());
+
+    bar.finish().await;
+    assert!(output
+        .last_frame()
+        .unwrap()
+        .starts_with("[==========] 100%"));
+    assert!(output.is_finished());
+}
+
+#[tokio::test]
+async fn test_renderer_captures_throbber_frames() {
+    let output = TestRenderer::new();
+    let config = ThrobberConfig::builder()
+        .colors(None)
+        .renderer(Some(output.factory()))
+        .frames(vec!["*".to_string()])
+        .frame_delay(20)
+        .build();
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("Indexing").await;
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(60)).await;
+    assert_eq!(output.last_frame().as_deref(), Some("* Indexing"));
+
+    throbber.stop().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(60)).await;
+    assert_eq!(output.last_frame().as_deref(), Some(""));
+    assert!(output.is_finished());
+}
@@ -0,0 +1,154 @@
+#[tokio::test]
+async fn test_throbber_runtime_frames() {
+    let throbber = throbberous::Throbber::new_plain();
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    let frames: Vec<String> = (1..=3).map(|n| format!("[{n}]")).collect();
+    throbber.set_frames(frames).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    throbber.stop_success("Done").await;
+}
+
+#[tokio::test]
+async fn test_throbber_restarts_after_stop() {
+    let throbber = throbberous::Throbber::new_plain();
+    throbber.set_message("Waiting").await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    throbber.stop().await;
+    let drawn = throbber.render_stats().frames_drawn;
+    assert!(drawn > 0);
+
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(400)).await;
+    assert!(throbber.render_stats().frames_drawn > drawn);
+    throbber.stop_success("Done").await;
+}
+
+#[tokio::test]
+async fn test_throbber_run_passes_result_through() {
+    let throbber = throbberous::Throbber::new_plain();
+    throbber.set_message("Resolving").await;
+    let ok: Result<u32, String> = throbber.run(async { Ok(7) }).await;
+    assert_eq!(ok, Ok(7));
+
+    let err: Result<(), std::io::Error> = throbber
+        .run(async {
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            Err(std::io::Error::other("network unreachable"))
+        })
+        .await;
+    assert!(err.is_err());
+}
+
+#[tokio::test]
+async fn test_throbber_elapsed() {
+    let config = throbberous::ThrobberConfig::builder()
+        .colors(None)
+        .show_elapsed(true)
+        .build();
+    let throbber = throbberous::Throbber::with_config(config);
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(1200)).await;
+    throbber.stop_success("Built").await;
+    assert!(throbber.render_stats().frames_drawn > 0);
+}
+
+#[tokio::test]
+async fn test_throbber_deadline() {
+    let config = throbberous::ThrobberConfig::builder()
+        .colors(None)
+        .frame_delay(20)
+        .deadline(Some(throbberous::Deadline {
+            soft: Some(std::time::Duration::from_millis(100)),
+            slow_message: Some("Still working, this is taking longer than usual".into()),
+            hard: Some(std::time::Duration::from_millis(300)),
+            ..Default::default()
+        }))
+        .build();
+    let throbber = throbberous::Throbber::with_config(config);
+    throbber.set_message("Connecting").await;
+    throbber.start().await;
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    assert_eq!(
+        throbber.message().await,
+        "Still working, this is taking longer than usual"
+    );
+
+    // Past the hard deadline the throbber has stopped on its own
+    tokio::time::sleep(tokio::time::Duration::from_millis(250)).await;
+    let drawn = throbber.render_stats().frames_drawn;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(throbber.render_stats().frames_drawn, drawn);
+}
+
+#[tokio::test]
+async fn test_throbber_custom_animator() {
+    let ticks = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let seen = ticks.clone();
+    let config = throbberous::ThrobberConfig::builder()
+        .colors(None)
+        .frame_delay(20)
+        .animator(Some(throbberous::shared_animator(
+            move |tick: u64, width: usize| {
+                seen.store(tick, std::sync::atomic::Ordering::Relaxed);
+                "~".repeat(width)
+            },
+        )))
+        .animation_width(3)
+        .build();
+    let throbber = throbberous::Throbber::with_config(config);
+    throbber.start().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+    throbber.stop().await;
+    assert!(ticks.load(std::sync::atomic::Ordering::Relaxed) > 0);
+}
+
+#[tokio::test]
+async fn test_cloned_throbber_shares_state() {
+    let throbber = throbberous::Throbber::new_plain();
+    throbber.start().await;
+    let clone = throbber.clone();
+    clone.set_message("Resolving").await;
+    drop(clone);
+
+    assert_eq!(throbber.message().await, "Resolving");
+    throbber.stop_success("Resolved").await;
+}
+
+#[tokio::test]
+async fn test_many_throbbers_all_animate() {
+    let ticks: Vec<_> = (0..50)
+        .map(|_| std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0)))
+        .collect();
+    let mut throbbers = Vec::new();
+    for count in &ticks {
+        let seen = count.clone();
+        let config = throbberous::ThrobberConfig::builder()
+            .colors(None)
+            .frame_delay(20)
+            .animator(Some(throbberous::shared_animator(
+                move |tick: u64, width: usize| {
+                    seen.store(tick, std::sync::atomic::Ordering::Relaxed);
+                    "~".repeat(width)
+                },
+            )))
+            .build();
+        let throbber = throbberous::Throbber::with_config(config);
+        throbber.start().await;
+        throbbers.push(throbber);
+    }
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    for throbber in &throbbers {
+        throbber.stop().await;
+    }
+    for count in &ticks {
+        assert!(count.load(std::sync::atomic::Ordering::Relaxed) > 0);
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "ratatui")]
+
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use throbberous::{Bar, BarConfig, TuiFrame};
+
+#[tokio::test]
+async fn test_tui_frame_renders_bar_into_buffer() {
+    let frame = TuiFrame::new();
+    let config = BarConfig::builder()
+        .colors(None)
+        .renderer(Some(frame.factory()))
+        .fit_to_terminal(false)
+        .width(10)
+        .build();
+    let bar = Bar::with_config(10, config);
+    bar.set_message("Syncing").await;
+    bar.inc(10).await;
+    bar.finish().await;
+    assert_eq!(frame.height(), 1);
+
+    let area = Rect::new(0, 0, 30, 2);
+    let mut buf = Buffer::empty(area);
+    (&frame).render(area, &mut buf);
+
+    let row: String = (0..area.width)
+        .map(|x| buf[(x, 0)].symbol().to_string())
+        .collect();
+    assert!(row.starts_with("[==========] 100% Syncing"));
+}
@@ -0,0 +1,93 @@
+#![cfg(unix)]
+//! Terminal write failures, driven in a child process whose stderr can't be written
+
+use std::{
+    process::{Command, Stdio},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use throbberous::{Bar, BarConfig, OutputMode};
+
+/// Runs the ignored test `name` in a child process with `stderr`, returning what it
+/// printed after `RESULT`
+fn run_child(name: &str, stderr: Stdio) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([name, "--exact", "--include-ignored", "--nocapture"])
+        .stderr(stderr)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    assert!(output.status.success(), "child failed:\n{}", stdout);
+    stdout
+        .lines()
+        .find_map(|line| line.split_once("RESULT ").map(|(_, result)| result))
+        .unwrap_or_else(|| panic!("no result in:\n{}", stdout))
+        .to_string()
+}
+
+/// Draws frames of a bar on the real stderr, printing how many errors the
+/// `on_error` hook had seen after each one
+async fn draw_to_failing_stderr() {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let seen = errors.clone();
+    let config = BarConfig::builder()
+        .colors(None)
+        .output(OutputMode::Terminal)
+        .refresh_rate(100)
+        .on_error(Some(Arc::new(move |err: &std::io::Error| {
+            seen.lock().unwrap().push(err.kind());
+        })))
+        .build();
+    let bar = Bar::indeterminate_manual("Working", config);
+
+    let mut calls = Vec::new();
+    for _ in 0..5 {
+        let drawn = bar.render_stats().frames_drawn;
+        bar.tick().await;
+        for _ in 0..200 {
+            if bar.render_stats().frames_drawn > drawn {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        calls.push(errors.lock().unwrap().len());
+    }
+    bar.finish().await;
+
+    println!("RESULT {:?} {:?}", calls, errors.lock().unwrap());
+}
+
+#[tokio::test]
+#[ignore = "run by test_terminal_goes_silent_after_repeated_failures"]
+async fn child_stderr_full() {
+    draw_to_failing_stderr().await;
+}
+
+#[tokio::test]
+#[ignore = "run by test_broken_pipe_stops_drawing"]
+async fn child_stderr_closed() {
+    draw_to_failing_stderr().await;
+}
+
+#[test]
+fn test_terminal_goes_silent_after_repeated_failures() {
+    let full = std::fs::OpenOptions::new()
+        .write(true)
+        .open("/dev/full")
+        .unwrap();
+    let result = run_child("child_stderr_full", full.into());
+    // Two failed frames are retried, the third turns the bar silent and calls
+    // the hook, which then isn't called again
+    assert!(result.starts_with("[0, 0, 1, 1, 1] "), "{}", result);
+    assert!(!result.contains("BrokenPipe"), "{}", result);
+}
+
+#[test]
+fn test_broken_pipe_stops_drawing() {
+    let (reader, writer) = std::io::pipe().unwrap();
+    drop(reader);
+    let result = run_child("child_stderr_closed", writer.into());
+    // Given up on right away, without a panic taking the child down
+    assert_eq!(result, "[1, 1, 1, 1, 1] [BrokenPipe]");
+}